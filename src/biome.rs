@@ -0,0 +1,153 @@
+use serde::{Deserialize, Serialize};
+
+use crate::world::Terrain;
+
+/// Хто може стояти на клітинці біому.
+#[derive(Clone, Copy, PartialEq, Debug, Serialize, Deserialize)]
+pub enum Passability {
+    Open,
+    /// Стіна для всіх (океан).
+    Wall,
+    /// Стіна для ситих Валкараїв; голодні, ті, що тікають, і зомбі проходять (пустеля).
+    DireOnly,
+}
+
+/// Правила одного біому.
+///
+/// Кліматична ніша — рядок таблиці Віттекера: біом обирається, якщо висота, температура
+/// й вологість клітинки потрапляють у його діапазони `[min, max]`. Перевіряються по черзі,
+/// останній у списку — запасний. Пропущений діапазон охоплює все.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct BiomeDef {
+    pub terrain: Terrain,
+    /// Висота, -1..1; рівень моря близько нуля.
+    #[serde(default = "full_elevation")]
+    pub elevation: [f32; 2],
+    /// Температура, 0 (полюс) .. 1 (екватор).
+    #[serde(default = "full_unit")]
+    pub temperature: [f32; 2],
+    /// Вологість, 0..1.
+    #[serde(default = "full_unit")]
+    pub moisture: [f32; 2],
+    /// RGB у діапазоні 0..1.
+    pub color: [f32; 3],
+    pub speed: f32,
+    /// Ємність: до скількох одиниць їжі доростають рослини клітинки. Нуль — нічого не росте.
+    pub capacity: f32,
+    /// Швидкість логістичного росту рослин за тік.
+    pub growth: f32,
+    pub passable: Passability,
+    /// У скільки разів їжа тут здається далі, ніж є насправді.
+    pub food_score: f32,
+}
+
+impl BiomeDef {
+    /// Середина кліматичної ніші: висота, температура, вологість.
+    pub fn niche_center(&self) -> (f32, f32, f32) {
+        let mid = |r: [f32; 2]| (r[0] + r[1]) / 2.0;
+        (mid(self.elevation), mid(self.temperature), mid(self.moisture))
+    }
+}
+
+/// Набір біомів: кліматичні ніші, правила руху та рослин.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct BiomeSet {
+    pub name: String,
+    pub biomes: Vec<BiomeDef>,
+}
+
+impl BiomeSet {
+    pub const PRESETS: [&'static str; 2] = ["tundra", "continental"];
+
+    pub fn preset(name: &str) -> Option<Self> {
+        match name {
+            "tundra" => Some(Self::tundra()),
+            "continental" => Some(Self::continental()),
+            _ => None,
+        }
+    }
+
+    /// Три біоми, повністю прохідні: гори вгорі, тундра в холоді, ліс у теплі.
+    pub fn tundra() -> Self {
+        BiomeSet {
+            name: "tundra".into(),
+            biomes: vec![
+                biome(Terrain::Mountain, [[0.35, 1.0], FULL_UNIT, FULL_UNIT], [0.3, 0.3, 0.35], 0.2, (1.0, 0.003), Passability::Open, 1.0),
+                biome(Terrain::Tundra, [FULL_ELEVATION, [0.0, 0.45], FULL_UNIT], [0.9, 0.9, 1.0], 1.0, (2.0, 0.003), Passability::Open, 1.0),
+                biome(Terrain::Forest, [FULL_ELEVATION, [0.45, 1.0], FULL_UNIT], [0.0, 0.3, 0.1], 0.6, (3.5, 0.003), Passability::Open, 1.0),
+            ],
+        }
+    }
+
+    /// П'ять біомів з океаном-стіною та пустелею, куди ситі Валкараї не ходять.
+    pub fn continental() -> Self {
+        BiomeSet {
+            name: "continental".into(),
+            biomes: vec![
+                // Мінімальна швидкість, щоб не застрягнути "всередині" клітинки
+                biome(Terrain::Ocean, [[-1.0, -0.15], FULL_UNIT, FULL_UNIT], [0.0, 0.47, 0.95], 0.1, (0.0, 0.0), Passability::Wall, 1.0),
+                biome(Terrain::Mountain, [[0.4, 1.0], FULL_UNIT, FULL_UNIT], [0.31, 0.31, 0.31], 0.2, (1.5, 0.003), Passability::Open, 1.0),
+                biome(Terrain::Desert, [FULL_ELEVATION, [0.55, 1.0], [0.0, 0.4]], [0.99, 0.98, 0.0], 0.7, (1.0, 0.003), Passability::DireOnly, 3.0),
+                biome(Terrain::Forest, [FULL_ELEVATION, FULL_UNIT, [0.55, 1.0]], [0.0, 0.46, 0.17], 0.6, (6.0, 0.003), Passability::Open, 1.0),
+                biome(Terrain::Plains, [FULL_ELEVATION, FULL_UNIT, FULL_UNIT], [0.4, 0.7, 0.2], 1.0, (4.0, 0.003), Passability::Open, 1.0),
+            ],
+        }
+    }
+
+    /// Правила для `terrain`. Клітинки світу завжди мають рельєф зі свого набору.
+    pub fn get(&self, terrain: Terrain) -> &BiomeDef {
+        self.biomes.iter().find(|b| b.terrain == terrain)
+            .unwrap_or_else(|| panic!("terrain {:?} is not part of biome set `{}`", terrain, self.name))
+    }
+
+    pub fn contains(&self, terrain: Terrain) -> bool {
+        self.biomes.iter().any(|b| b.terrain == terrain)
+    }
+
+    pub fn terrains(&self) -> impl Iterator<Item = Terrain> + '_ {
+        self.biomes.iter().map(|b| b.terrain)
+    }
+
+    /// Перший біом, у чию нішу потрапляє клімат клітинки; інакше останній.
+    pub fn classify(&self, elevation: f32, temperature: f32, moisture: f32) -> Terrain {
+        let within = |r: [f32; 2], v: f32| (r[0]..=r[1]).contains(&v);
+        self.biomes.iter()
+            .find(|b| within(b.elevation, elevation) && within(b.temperature, temperature) && within(b.moisture, moisture))
+            .unwrap_or(&self.biomes[self.biomes.len() - 1]).terrain
+    }
+
+    pub fn validate(&self) -> Result<(), String> {
+        if self.biomes.is_empty() { return Err(format!("biome set `{}` has no biomes", self.name)); }
+        for (i, b) in self.biomes.iter().enumerate() {
+            let name = b.terrain.name();
+            if self.biomes[..i].iter().any(|o| o.terrain == b.terrain) { return Err(format!("biome `{}` is listed twice", name)); }
+            for (key, r, lo, hi) in [("elevation", b.elevation, -1.0, 1.0), ("temperature", b.temperature, 0.0, 1.0), ("moisture", b.moisture, 0.0, 1.0)] {
+                if !(lo <= r[0] && r[0] <= r[1] && r[1] <= hi) {
+                    return Err(format!("biome `{}`: {} range [{}, {}] must be ordered and within [{}, {}]", name, key, r[0], r[1], lo, hi));
+                }
+            }
+            if !(0.0..=10.0).contains(&b.speed) { return Err(format!("biome `{}`: speed {} is out of range [0, 10]", name, b.speed)); }
+            if !(0.0..=10_000.0).contains(&b.capacity) { return Err(format!("biome `{}`: capacity {} is out of range [0, 10000]", name, b.capacity)); }
+            if !(0.0..=1.0).contains(&b.growth) { return Err(format!("biome `{}`: growth {} is out of range [0, 1]", name, b.growth)); }
+            if !(0.1..=100.0).contains(&b.food_score) { return Err(format!("biome `{}`: food_score {} is out of range [0.1, 100]", name, b.food_score)); }
+            if b.color.iter().any(|c| !(0.0..=1.0).contains(c)) { return Err(format!("biome `{}`: color components must be in [0, 1]", name)); }
+        }
+        if self.biomes.iter().all(|b| b.passable == Passability::Wall) { return Err("at least one biome must be passable".into()); }
+        Ok(())
+    }
+}
+
+const FULL_ELEVATION: [f32; 2] = [-1.0, 1.0];
+const FULL_UNIT: [f32; 2] = [0.0, 1.0];
+
+fn full_elevation() -> [f32; 2] { FULL_ELEVATION }
+fn full_unit() -> [f32; 2] { FULL_UNIT }
+
+/// `niche` — висота, температура, вологість; `plants` — ємність і швидкість росту.
+fn biome(terrain: Terrain, niche: [[f32; 2]; 3], color: [f32; 3], speed: f32, plants: (f32, f32), passable: Passability, food_score: f32) -> BiomeDef {
+    let [elevation, temperature, moisture] = niche;
+    let (capacity, growth) = plants;
+    BiomeDef { terrain, elevation, temperature, moisture, color, speed, capacity, growth, passable, food_score }
+}
@@ -0,0 +1,39 @@
+use noise::{Fbm, MultiFractal, NoiseFn, Perlin};
+use rand::Rng;
+use rand_chacha::ChaCha8Rng;
+
+/// Три незалежні фрактальні поля, з яких складається клімат світу.
+pub(crate) struct Climate {
+    elevation: Fbm<Perlin>,
+    temperature: Fbm<Perlin>,
+    moisture: Fbm<Perlin>,
+}
+
+/// Наскільки холоднішає на кожну одиницю висоти над морем.
+const LAPSE_RATE: f32 = 0.5;
+/// Частка температури від широти; решта — від шуму.
+const LATITUDE_WEIGHT: f32 = 0.6;
+
+impl Climate {
+    pub(crate) fn new(rng: &mut ChaCha8Rng) -> Self {
+        Climate {
+            elevation: Fbm::<Perlin>::new(rng.gen()).set_octaves(5).set_frequency(0.02),
+            temperature: Fbm::<Perlin>::new(rng.gen()).set_octaves(3).set_frequency(0.01),
+            moisture: Fbm::<Perlin>::new(rng.gen()).set_octaves(4).set_frequency(0.03),
+        }
+    }
+
+    /// Висота (-1..1), температура і вологість (0..1) у клітинці `x, y` світу розміру `size`.
+    ///
+    /// Температура спадає від середини карти (екватор) до верхнього й нижнього країв (полюси)
+    /// і холоднішає з висотою.
+    pub(crate) fn sample(&self, x: usize, y: usize, size: usize) -> (f32, f32, f32) {
+        let p = [x as f64, y as f64];
+        let elevation = (self.elevation.get(p) as f32).clamp(-1.0, 1.0);
+        let latitude = 1.0 - (2.0 * (y as f32 + 0.5) / size as f32 - 1.0).abs();
+        let noise = self.temperature.get(p) as f32 * 0.5 + 0.5;
+        let temperature = LATITUDE_WEIGHT * latitude + (1.0 - LATITUDE_WEIGHT) * noise - LAPSE_RATE * elevation.max(0.0);
+        let moisture = self.moisture.get(p) as f32 * 0.5 + 0.5;
+        (elevation, temperature.clamp(0.0, 1.0), moisture.clamp(0.0, 1.0))
+    }
+}
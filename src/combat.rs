@@ -0,0 +1,56 @@
+use ::rand::Rng;
+use rand_chacha::ChaCha8Rng;
+
+use crate::config::SimConfig;
+use crate::world::{Agent, AgentKind, TickEvents};
+
+/// Імовірність, що Валкарай заразиться на цьому тіку контакту.
+pub(crate) fn infection_probability(exposure: u32, resistance_gen: f32, cfg: &SimConfig) -> f64 {
+    let p = cfg.infection_chance * (1.0 + cfg.exposure_factor * exposure as f32) * (1.0 - resistance_gen);
+    p.clamp(0.0, 1.0) as f64
+}
+
+/// Розігрує всі контакти зомбі з Валкараями за тік.
+///
+/// Спершу оновлює `exposure`: скільки тіків поспіль Валкарай стикається з зомбі.
+/// Кожна пара `(зомбі, валкарай)` обмінюється ударами по `damage * combat_rate`.
+/// Той, чиє здоров'я дійшло до нуля, гине.
+/// Живий Валкарай заражається з імовірністю `infection_probability`.
+/// Зі здоров'ям нижче `infection_health_fraction * max_health` він заражається напевно.
+/// Зомбі отримує `infection_energy` за зараження і `feed_energy` за вбитого, але не більше `zombie_energy`.
+/// Загиблих прибирає `World::update`. Повертає індекси щойно заражених.
+pub(crate) fn resolve(agents: &mut [Agent], contacts: &[(usize, usize)], cfg: &SimConfig, rng: &mut ChaCha8Rng, events: &mut TickEvents) -> Vec<usize> {
+    let mut touched = vec![false; agents.len()];
+    for &(_, v) in contacts { touched[v] = true; }
+    for (a, &t) in agents.iter_mut().zip(&touched) {
+        a.exposure = if t { a.exposure + 1 } else { 0 };
+    }
+
+    let mut infected = Vec::new();
+    for &(z, v) in contacts {
+        // Мертвий зомбі не б'є, а вже заражений чи вбитий валкарай не відбивається
+        if agents[z].health <= 0.0 || agents[v].kind != AgentKind::Valkarai || agents[v].health <= 0.0 { continue; }
+        let (hit, counter) = (agents[z].damage * cfg.combat_rate, agents[v].damage * cfg.combat_rate);
+        agents[v].health -= hit;
+        agents[z].health -= counter;
+        if agents[z].health <= 0.0 { events.zombies_killed += 1; }
+        let victim = &mut agents[v];
+        let gain = if victim.health <= 0.0 {
+            events.valkarai_killed += 1;
+            cfg.feed_energy
+        } else if victim.health < victim.max_health * cfg.infection_health_fraction
+            || rng.gen_bool(infection_probability(victim.exposure, victim.resistance_gen, cfg)) {
+            events.infections += 1;
+            infected.push(v);
+            victim.kind = AgentKind::Zombie;
+            victim.energy = cfg.zombie_energy;
+            victim.health = victim.max_health;
+            cfg.infection_energy
+        } else {
+            0.0
+        };
+        // Мертвий зомбі вже не їсть
+        if agents[z].health > 0.0 { agents[z].energy = (agents[z].energy + gain).min(cfg.zombie_energy); }
+    }
+    infected
+}
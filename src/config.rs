@@ -0,0 +1,166 @@
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::path::Path;
+
+use crate::biome::{BiomeDef, BiomeSet};
+
+/// Параметри симуляції. `Default` — поточний баланс; `sim.example.toml` повторює його дослівно.
+///
+/// Файл може містити лише частину ключів — решта береться з `Default`.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct SimConfig {
+    pub grid_size: usize,
+    pub mating_distance: f32,
+    pub cooldown_time: f32,
+    pub reproduction_threshold: f32,
+    /// Назва набору біомів: `tundra` або `continental`.
+    pub biome_set: String,
+    /// Якщо не порожній — замінює біоми обраного набору (`[[biomes]]` у TOML).
+    pub biomes: Vec<BiomeDef>,
+    /// Нижче цієї енергії Валкарай вважається голодним і йде навіть у пустелю.
+    pub dire_energy: f32,
+    pub initial_valkarai: usize,
+    /// Енергія щойно зараженого зомбі; більше за неї зомбі не наїдається.
+    pub zombie_energy: f32,
+    /// Скільки енергії зомбі витрачає за тік.
+    pub zombie_metabolism: f32,
+    /// Скільки енергії зомбі отримує за кожного зараженого.
+    pub infection_energy: f32,
+    /// Скільки енергії зомбі отримує, з'ївши вбитого Валкарая.
+    pub feed_energy: f32,
+    /// Частка `damage`, яку агент завдає за один тік контакту.
+    pub combat_rate: f32,
+    /// Нижче цієї частки від `max_health` поранений Валкарай заражається напевно, хоч який у нього опір.
+    pub infection_health_fraction: f32,
+    /// Імовірність зараження за один тік контакту з зомбі для Валкарая без опору.
+    pub infection_chance: f32,
+    /// На скільки (у частках `infection_chance`) зростає імовірність за кожен тік безперервного контакту.
+    pub exposure_factor: f32,
+    /// Скільки здоров'я агент відновлює за тік.
+    pub health_regen: f32,
+    /// Скільки здоров'я зомбі втрачає за тік від розкладу (замість відновлення).
+    pub zombie_decay: f32,
+    /// Швидкість, з якою рослини засівають сусідні клітинки (у частках ємності за тік).
+    pub seed_rate: f32,
+    /// Скільки сили (`vigor`, 0..1) втрачає клітинка, об'їдена до нуля.
+    pub grazing_damage: f32,
+    /// Скільки сили клітинка відновлює за тік.
+    pub vigor_recovery: f32,
+}
+
+impl Default for SimConfig {
+    fn default() -> Self {
+        SimConfig {
+            grid_size: 250,
+            mating_distance: 1.2,
+            cooldown_time: 150.0,
+            reproduction_threshold: 90.0,
+            biome_set: "tundra".into(),
+            biomes: Vec::new(),
+            dire_energy: 40.0,
+            initial_valkarai: 40,
+            zombie_energy: 300.0,
+            zombie_metabolism: 0.15,
+            infection_energy: 80.0,
+            feed_energy: 120.0,
+            combat_rate: 0.25,
+            infection_health_fraction: 0.4,
+            infection_chance: 0.02,
+            exposure_factor: 0.25,
+            health_regen: 0.05,
+            zombie_decay: 0.02,
+            seed_rate: 0.0005,
+            grazing_damage: 0.5,
+            vigor_recovery: 0.001,
+        }
+    }
+}
+
+#[derive(Debug)]
+pub enum ConfigError {
+    Io(std::io::Error),
+    Parse(String),
+    Invalid(String),
+}
+
+impl fmt::Display for ConfigError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ConfigError::Io(e) => write!(f, "cannot read config: {}", e),
+            ConfigError::Parse(e) => write!(f, "cannot parse config: {}", e),
+            ConfigError::Invalid(e) => write!(f, "invalid config: {}", e),
+        }
+    }
+}
+
+impl std::error::Error for ConfigError {}
+
+impl SimConfig {
+    /// Читає `.toml` або `.json` (за розширенням) і перевіряє значення.
+    pub fn load(path: impl AsRef<Path>) -> Result<Self, ConfigError> {
+        let path = path.as_ref();
+        let text = std::fs::read_to_string(path).map_err(ConfigError::Io)?;
+        match path.extension().and_then(|e| e.to_str()) {
+            Some("json") => Self::from_json_str(&text),
+            Some("toml") => Self::from_toml_str(&text),
+            other => Err(ConfigError::Parse(format!("unsupported config extension {:?}, expected .toml or .json", other.unwrap_or("")))),
+        }
+    }
+
+    pub fn from_toml_str(text: &str) -> Result<Self, ConfigError> {
+        let cfg: SimConfig = toml::from_str(text).map_err(|e| ConfigError::Parse(e.to_string()))?;
+        cfg.validate()?;
+        Ok(cfg)
+    }
+
+    pub fn from_json_str(text: &str) -> Result<Self, ConfigError> {
+        let cfg: SimConfig = serde_json::from_str(text).map_err(|e| ConfigError::Parse(e.to_string()))?;
+        cfg.validate()?;
+        Ok(cfg)
+    }
+
+    /// Перевіряє, що всі значення в розумних межах.
+    pub fn validate(&self) -> Result<(), ConfigError> {
+        check_range("grid_size", self.grid_size as f64, 10.0, 4000.0)?;
+        check_range("mating_distance", self.mating_distance as f64, 0.01, 50.0)?;
+        check_range("cooldown_time", self.cooldown_time as f64, 0.0, 1e6)?;
+        check_range("reproduction_threshold", self.reproduction_threshold as f64, 50.0, 1e6)?;
+        if BiomeSet::preset(&self.biome_set).is_none() {
+            return Err(ConfigError::Invalid(format!("unknown biome_set `{}`, expected one of {:?}", self.biome_set, BiomeSet::PRESETS)));
+        }
+        self.biome_set().validate().map_err(ConfigError::Invalid)?;
+        check_range("dire_energy", self.dire_energy as f64, 0.0, 1e6)?;
+        check_range("initial_valkarai", self.initial_valkarai as f64, 0.0, 100_000.0)?;
+        check_range("zombie_energy", self.zombie_energy as f64, 1.0, 1e9)?;
+        check_range("zombie_metabolism", self.zombie_metabolism as f64, 0.0, 1000.0)?;
+        check_range("infection_energy", self.infection_energy as f64, 0.0, 1e9)?;
+        check_range("feed_energy", self.feed_energy as f64, 0.0, 1e9)?;
+        check_range("combat_rate", self.combat_rate as f64, 0.0, 10.0)?;
+        check_range("infection_health_fraction", self.infection_health_fraction as f64, 0.0, 1.0)?;
+        check_range("infection_chance", self.infection_chance as f64, 0.0, 1.0)?;
+        check_range("exposure_factor", self.exposure_factor as f64, 0.0, 100.0)?;
+        check_range("health_regen", self.health_regen as f64, 0.0, 100.0)?;
+        check_range("zombie_decay", self.zombie_decay as f64, 0.0, 1000.0)?;
+        check_range("seed_rate", self.seed_rate as f64, 0.0, 0.1)?;
+        check_range("grazing_damage", self.grazing_damage as f64, 0.0, 1.0)?;
+        check_range("vigor_recovery", self.vigor_recovery as f64, 0.0, 1.0)?;
+        Ok(())
+    }
+}
+
+impl SimConfig {
+    /// Набір біомів з урахуванням власних `biomes`. Конфіг має пройти `validate`.
+    pub fn biome_set(&self) -> BiomeSet {
+        let mut set = BiomeSet::preset(&self.biome_set).unwrap_or_else(|| panic!("unknown biome_set `{}`", self.biome_set));
+        if !self.biomes.is_empty() { set.biomes = self.biomes.clone(); }
+        set
+    }
+}
+
+fn check_range(key: &str, value: f64, min: f64, max: f64) -> Result<(), ConfigError> {
+    if value.is_nan() || value < min || value > max {
+        return Err(ConfigError::Invalid(format!("`{}` = {} is out of range [{}, {}]", key, value, min, max)));
+    }
+    Ok(())
+}
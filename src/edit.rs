@@ -0,0 +1,96 @@
+//! Правки світу з редактора: пензлі рельєфу та їжі, нові агенти, гумка.
+//!
+//! Усе пишеться прямо в `cells`, `agents` і `lineage`, тому потрапляє у знімки.
+
+use crate::biome::Passability;
+use crate::lineage::DeathCause;
+use crate::world::{cell_of, Agent, Terrain, World, DEFAULT_LIFESPAN};
+use glam::Vec2;
+
+impl World {
+    /// Клітинки, чиї центри лежать у колі.
+    fn cells_in(&self, center: Vec2, radius: f32) -> Vec<(usize, usize)> {
+        let size = self.size() as i64;
+        let (lo_x, hi_x) = ((center.x - radius).floor() as i64, (center.x + radius).ceil() as i64);
+        let (lo_y, hi_y) = ((center.y - radius).floor() as i64, (center.y + radius).ceil() as i64);
+        let mut out = Vec::new();
+        for x in lo_x.max(0)..=hi_x.min(size - 1) {
+            for y in lo_y.max(0)..=hi_y.min(size - 1) {
+                let c = Vec2::new(x as f32 + 0.5, y as f32 + 0.5);
+                if c.distance(center) <= radius.max(0.5) { out.push((x as usize, y as usize)); }
+            }
+        }
+        out
+    }
+
+    /// Фарбує коло рельєфом; повертає кількість змінених клітинок.
+    ///
+    /// Клімат клітинки ставиться в середину ніші нового біому. Стіну не малюємо під агентами,
+    /// щоб вони не застрягли всередині. Панікує, якщо `terrain` немає в наборі біомів світу.
+    pub fn paint_terrain(&mut self, center: Vec2, radius: f32, terrain: Terrain) -> usize {
+        assert!(self.biomes.contains(terrain), "terrain {:?} is not part of biome set `{}`", terrain, self.biomes.name);
+        let biome = self.biomes.get(terrain);
+        let (elevation, temperature, moisture) = biome.niche_center();
+        let wall = biome.passable == Passability::Wall;
+        let mut painted = 0;
+        for (x, y) in self.cells_in(center, radius) {
+            if wall && self.agents.iter().any(|a| cell_of(a.pos) == (x, y)) { continue; }
+            let cell = &mut self.cells[x][y];
+            cell.terrain = terrain;
+            (cell.elevation, cell.temperature, cell.moisture) = (elevation, temperature, moisture);
+            painted += 1;
+        }
+        painted
+    }
+
+    /// Додає `amount` їжі кожній клітинці в колі.
+    pub fn add_food(&mut self, center: Vec2, radius: f32, amount: f32) {
+        for (x, y) in self.cells_in(center, radius) { self.cells[x][y].food_level += amount; }
+    }
+
+    /// Прибирає всю їжу в колі.
+    pub fn clear_food(&mut self, center: Vec2, radius: f32) {
+        for (x, y) in self.cells_in(center, radius) { self.cells[x][y].food_level = 0.0; }
+    }
+
+    /// Чи можна щось поставити в точку: в межах світу і не в стіні.
+    fn can_place(&self, pos: Vec2) -> bool {
+        let size = self.size() as f32;
+        pos.x >= 0.0 && pos.y >= 0.0 && pos.x < size && pos.y < size
+            && self.biomes.get(self.cells[pos.x as usize][pos.y as usize].terrain).passable != Passability::Wall
+    }
+
+    fn spawn(&mut self, agent: Agent) -> u64 {
+        self.lineage.record_birth(&agent);
+        let id = agent.id;
+        self.agents.push(agent);
+        self.grid.rebuild(&self.agents);
+        id
+    }
+
+    /// Ставить Валкарая-засновника з заданими генами; `None`, якщо точка поза світом чи в стіні.
+    pub fn spawn_valkarai(&mut self, pos: Vec2, speed_gen: f32, vision_gen: f32, resistance_gen: f32) -> Option<u64> {
+        if !self.can_place(pos) { return None; }
+        let (speed_gen, vision_gen, resistance_gen) = (speed_gen.clamp(0.08, 0.3), vision_gen.clamp(8.0, 30.0), resistance_gen.clamp(0.0, 1.0));
+        let agent = Agent::founder(self.lineage.next_id(), pos, speed_gen, vision_gen, DEFAULT_LIFESPAN, resistance_gen, self.tick);
+        Some(self.spawn(agent))
+    }
+
+    /// Ставить нового зомбі з енергією `zombie_energy`; `None`, якщо точка поза світом чи в стіні.
+    pub fn spawn_zombie(&mut self, pos: Vec2) -> Option<u64> {
+        if !self.can_place(pos) { return None; }
+        let agent = Agent::patient_zero(self.lineage.next_id(), pos, self.config.zombie_energy, self.tick);
+        Some(self.spawn(agent))
+    }
+
+    /// Прибирає всіх агентів у колі й повертає, скільки їх було.
+    pub fn erase_agents(&mut self, center: Vec2, radius: f32) -> usize {
+        let before = self.agents.len();
+        for a in self.agents.iter().filter(|a| a.pos.distance(center) <= radius) {
+            self.lineage.record_death(a.id, self.tick, DeathCause::Removed);
+        }
+        self.agents.retain(|a| a.pos.distance(center) > radius);
+        self.grid.rebuild(&self.agents);
+        before - self.agents.len()
+    }
+}
@@ -0,0 +1,97 @@
+use macroquad::prelude::*;
+use quadrisrah_sim::World;
+use std::ops::Range;
+
+/// Найбільше наближення, пікселів на клітинку.
+const MAX_ZOOM: f32 = 80.0;
+/// Швидкість панорамування клавішами, пікселів за секунду.
+const PAN_SPEED: f32 = 600.0;
+
+/// Камера над світом: центр у координатах світу і масштаб у пікселях на клітинку.
+pub struct Camera {
+    pub center: Vec2,
+    pub zoom: f32,
+    /// `id` агента, за яким стежить камера.
+    pub follow: Option<u64>,
+    drag_from: Option<Vec2>,
+}
+
+impl Camera {
+    /// Вміщує весь світ в екран.
+    pub fn fit(world_size: usize) -> Self {
+        Camera { center: Vec2::splat(world_size as f32 / 2.0), zoom: Self::fit_zoom(world_size), follow: None, drag_from: None }
+    }
+
+    fn fit_zoom(world_size: usize) -> f32 {
+        screen_width().min(screen_height()) / world_size as f32
+    }
+
+    fn screen_center() -> Vec2 { vec2(screen_width(), screen_height()) / 2.0 }
+
+    pub fn world_to_screen(&self, p: Vec2) -> Vec2 { (p - self.center) * self.zoom + Self::screen_center() }
+
+    pub fn screen_to_world(&self, s: Vec2) -> Vec2 { (s - Self::screen_center()) / self.zoom + self.center }
+
+    /// Діапазони клітинок, що хоч частково потрапляють на екран.
+    pub fn visible_cells(&self, world_size: usize) -> (Range<usize>, Range<usize>) {
+        let tl = self.screen_to_world(Vec2::ZERO);
+        let br = self.screen_to_world(vec2(screen_width(), screen_height()));
+        let clip = |v: f32| (v.max(0.0) as usize).min(world_size);
+        (clip(tl.x.floor())..clip(br.x.ceil()), clip(tl.y.floor())..clip(br.y.ceil()))
+    }
+
+    pub fn is_visible(&self, s: Vec2, margin: f32) -> bool {
+        s.x >= -margin && s.y >= -margin && s.x <= screen_width() + margin && s.y <= screen_height() + margin
+    }
+
+    /// Коліщатко — масштаб відносно курсора, права кнопка чи WASD — зсув, Home — весь світ.
+    pub fn handle_input(&mut self, world_size: usize) {
+        let mouse = Vec2::from(mouse_position());
+        let wheel = mouse_wheel().1;
+        if wheel != 0.0 {
+            let anchor = self.screen_to_world(mouse);
+            let factor = if wheel > 0.0 { 1.15 } else { 1.0 / 1.15 };
+            self.zoom = (self.zoom * factor).clamp(Self::fit_zoom(world_size) * 0.5, MAX_ZOOM);
+            self.center += anchor - self.screen_to_world(mouse);
+        }
+
+        if is_mouse_button_down(MouseButton::Right) {
+            if let Some(from) = self.drag_from {
+                self.center -= (mouse - from) / self.zoom;
+                self.follow = None;
+            }
+            self.drag_from = Some(mouse);
+        } else {
+            self.drag_from = None;
+        }
+
+        let mut pan = Vec2::ZERO;
+        if is_key_down(KeyCode::W) { pan.y -= 1.0; }
+        if is_key_down(KeyCode::S) { pan.y += 1.0; }
+        if is_key_down(KeyCode::A) { pan.x -= 1.0; }
+        if is_key_down(KeyCode::D) { pan.x += 1.0; }
+        if pan != Vec2::ZERO {
+            self.center += pan * PAN_SPEED * get_frame_time() / self.zoom;
+            self.follow = None;
+        }
+
+        if is_key_pressed(KeyCode::Home) { *self = Camera::fit(world_size); }
+        self.center = self.center.clamp(Vec2::ZERO, Vec2::splat(world_size as f32));
+    }
+
+    /// Ставить точку світу `p` у точку екрана `screen`.
+    pub fn look_at(&mut self, p: Vec2, screen: Vec2) {
+        self.center = p - (screen - Self::screen_center()) / self.zoom;
+        self.follow = None;
+    }
+
+    /// Тримає агента, за яким стежимо, по центру; якщо він зник — перестаємо стежити.
+    pub fn track(&mut self, world: &World) {
+        if let Some(id) = self.follow {
+            match world.agent_by_id(id) {
+                Some(a) => self.center = a.pos,
+                None => self.follow = None,
+            }
+        }
+    }
+}
@@ -0,0 +1,101 @@
+use macroquad::prelude::*;
+use quadrisrah_sim::{AgentKind, StatsRecorder, World};
+
+use super::terrain_color;
+
+/// Скільки останніх тіків показують часові ряди.
+pub const HISTORY: usize = 600;
+const BINS: usize = 20;
+const PANEL_H: f32 = 220.0;
+
+fn panel_rect() -> Rect {
+    Rect::new(20.0, screen_height() - PANEL_H - 40.0, (screen_width() - 40.0).min(1500.0), PANEL_H)
+}
+
+/// Чи курсор над панеллю графіків.
+pub fn contains_mouse() -> bool {
+    panel_rect().contains(Vec2::from(mouse_position()))
+}
+
+/// Ламана по значеннях, розтягнута на `r`; `max` — верх осі.
+fn draw_series(r: Rect, values: impl Iterator<Item = f32>, len: usize, max: f32, color: Color) {
+    if len < 2 || max <= 0.0 { return; }
+    let step = r.w / (len - 1) as f32;
+    let mut prev: Option<Vec2> = None;
+    for (i, v) in values.enumerate() {
+        let p = vec2(r.x + i as f32 * step, r.bottom() - (v / max).min(1.0) * r.h);
+        if let Some(q) = prev { draw_line(q.x, q.y, p.x, p.y, 1.5, color); }
+        prev = Some(p);
+    }
+}
+
+fn draw_frame(r: Rect, title: &str) {
+    draw_rectangle_lines(r.x, r.y, r.w, r.h, 1.0, DARKGRAY);
+    draw_text(title, r.x + 4.0, r.y - 6.0, 18.0, WHITE);
+}
+
+/// Гістограма гена по живих Валкараях на відрізку `[lo, hi]`.
+fn draw_histogram(r: Rect, title: &str, values: &[f32], lo: f32, hi: f32, color: Color) {
+    draw_frame(r, title);
+    let mut bins = [0usize; BINS];
+    for &v in values {
+        let b = (((v - lo) / (hi - lo)) * BINS as f32) as usize;
+        bins[b.min(BINS - 1)] += 1;
+    }
+    let peak = *bins.iter().max().unwrap_or(&0);
+    if peak == 0 { return; }
+    let w = r.w / BINS as f32;
+    for (i, &n) in bins.iter().enumerate() {
+        let h = n as f32 / peak as f32 * (r.h - 4.0);
+        draw_rectangle(r.x + i as f32 * w + 1.0, r.bottom() - h, w - 2.0, h, color);
+    }
+    draw_text(&format!("{}", lo), r.x, r.bottom() + 14.0, 14.0, GRAY);
+    let hi_str = format!("{}", hi);
+    draw_text(&hi_str, r.right() - measure_text(&hi_str, None, 14, 1.0).width, r.bottom() + 14.0, 14.0, GRAY);
+}
+
+/// Популяції в часі, розподіли генів і запас їжі по біомах. Малюється з уже записаної
+/// статистики й поточного стану світу, тож працює і на паузі.
+pub fn draw(stats: &StatsRecorder, world: &World) {
+    let r = panel_rect();
+    draw_rectangle(r.x, r.y, r.w, r.h, Color::new(0.0, 0.0, 0.0, 0.85));
+    draw_rectangle_lines(r.x, r.y, r.w, r.h, 2.0, GRAY);
+
+    let gap = 20.0;
+    let w = (r.w - gap * 6.0) / 5.0;
+    let chart = |i: usize| Rect::new(r.x + gap + i as f32 * (w + gap), r.y + 30.0, w, r.h - 60.0);
+    let rows = stats.rows();
+    let recent = &rows[rows.len().saturating_sub(HISTORY)..];
+
+    // Валкараї проти зомбі
+    let c = chart(0);
+    draw_frame(c, "Population");
+    let peak = recent.iter().map(|s| s.valkarai.max(s.zombies)).max().unwrap_or(0) as f32;
+    draw_series(c, recent.iter().map(|s| s.valkarai as f32), recent.len(), peak, RED);
+    draw_series(c, recent.iter().map(|s| s.zombies as f32), recent.len(), peak, PURPLE);
+    if let Some(last) = recent.last() {
+        draw_text(&format!("V {}  Z {}  (max {})", last.valkarai, last.zombies, peak), c.x, c.bottom() + 14.0, 14.0, GRAY);
+    }
+
+    // Межі такі самі, як у clamp при схрещуванні
+    let valkarai: Vec<_> = world.agents_of(AgentKind::Valkarai).collect();
+    let speed: Vec<f32> = valkarai.iter().map(|a| a.speed_gen).collect();
+    let vision: Vec<f32> = valkarai.iter().map(|a| a.vision_gen).collect();
+    draw_histogram(chart(1), "speed_gen", &speed, 0.08, 0.3, ORANGE);
+    draw_histogram(chart(2), "vision_gen", &vision, 8.0, 30.0, SKYBLUE);
+    let resistance: Vec<f32> = valkarai.iter().map(|a| a.resistance_gen).collect();
+    draw_histogram(chart(3), "resistance_gen", &resistance, 0.0, 1.0, LIME);
+
+    // Їжа по біомах
+    let c = chart(4);
+    draw_frame(c, "Food per terrain");
+    if let Some(last) = recent.last() {
+        let peak = recent.iter().flat_map(|s| s.food.iter().map(|f| f.1)).fold(0.0f32, f32::max);
+        for (k, &(terrain, _)) in last.food.iter().enumerate() {
+            // Після зміни набору біомів рядки очищаються, тож індекс збігається в усіх рядках
+            let color = terrain_color(world, terrain);
+            draw_series(c, recent.iter().map(|s| s.food[k].1), recent.len(), peak, color);
+            draw_text(terrain.name(), c.x + k as f32 * 60.0, c.bottom() + 14.0, 14.0, color);
+        }
+    }
+}
@@ -0,0 +1,64 @@
+use quadrisrah_sim::{StatsRecorder, World};
+use std::time::{Duration, Instant};
+
+/// Тіків за кадр на кожній швидкості; `None` — «max».
+const LEVELS: [Option<u32>; 5] = [Some(1), Some(2), Some(10), Some(100), None];
+/// Крок симуляції на 1×: 60 тіків за секунду реального часу.
+const TICK_DT: f64 = 1.0 / 60.0;
+/// Більший за це кадр (згорнуте вікно, ривок) не доганяємо.
+const MAX_FRAME_TIME: f64 = 0.25;
+/// Скільки часу кадру «max» віддає на тіки, щоб вікно лишалося живим.
+const MAX_BUDGET: Duration = Duration::from_millis(12);
+
+/// Відв'язує темп симуляції від частоти кадрів: накопичує реальний час і проганяє
+/// стільки тіків, скільки в нього влазить на поточній швидкості.
+pub struct SimClock {
+    level: usize,
+    acc: f64,
+}
+
+impl SimClock {
+    pub fn new() -> Self { SimClock { level: 0, acc: 0.0 } }
+
+    pub fn faster(&mut self) { self.level = (self.level + 1).min(LEVELS.len() - 1); }
+    pub fn slower(&mut self) { self.level = self.level.saturating_sub(1); }
+
+    pub fn label(&self) -> String {
+        match LEVELS[self.level] {
+            Some(n) => format!("{}x", n),
+            None => "max".to_owned(),
+        }
+    }
+
+    /// Один тік з записом статистики.
+    pub fn step(world: &mut World, stats: &mut StatsRecorder) {
+        world.update();
+        stats.record(world);
+    }
+
+    /// Проганяє тіки, що накопичились за `frame_time` секунд, і повертає їх кількість.
+    pub fn advance(&mut self, world: &mut World, stats: &mut StatsRecorder, frame_time: f64) -> u32 {
+        match LEVELS[self.level] {
+            Some(per_step) => {
+                self.acc += frame_time.min(MAX_FRAME_TIME);
+                let steps = (self.acc / TICK_DT) as u32;
+                self.acc -= steps as f64 * TICK_DT;
+                for _ in 0..steps * per_step { Self::step(world, stats); }
+                steps * per_step
+            }
+            None => {
+                self.acc = 0.0;
+                let start = Instant::now();
+                let mut done = 0;
+                while start.elapsed() < MAX_BUDGET {
+                    Self::step(world, stats);
+                    done += 1;
+                }
+                done
+            }
+        }
+    }
+
+    /// На паузі час не накопичується, щоб після неї не було ривка.
+    pub fn hold(&mut self) { self.acc = 0.0; }
+}
@@ -0,0 +1,111 @@
+use macroquad::prelude::*;
+use quadrisrah_sim::{Terrain, World};
+
+use super::camera::Camera;
+
+#[derive(Clone, Copy, PartialEq)]
+enum Tool { Terrain, AddFood, ClearFood, Valkarai, Zombie, Eraser }
+
+impl Tool {
+    const ALL: [Tool; 6] = [Tool::Terrain, Tool::AddFood, Tool::ClearFood, Tool::Valkarai, Tool::Zombie, Tool::Eraser];
+    const KEYS: [KeyCode; 6] = [KeyCode::Key1, KeyCode::Key2, KeyCode::Key3, KeyCode::Key4, KeyCode::Key5, KeyCode::Key6];
+
+    fn name(self) -> &'static str {
+        match self {
+            Tool::Terrain => "paint terrain", Tool::AddFood => "add food", Tool::ClearFood => "clear food",
+            Tool::Valkarai => "spawn Valkarai", Tool::Zombie => "spawn zombie", Tool::Eraser => "erase agents",
+        }
+    }
+
+    /// Пензлі діють, поки кнопку тримають; агенти ставляться по одному на клік.
+    fn is_brush(self) -> bool { !matches!(self, Tool::Valkarai | Tool::Zombie) }
+}
+
+/// Режим редагування (E): пензлі рельєфу й їжі, нові агенти, гумка. Працює й на паузі.
+pub struct Editor {
+    pub active: bool,
+    tool: Tool,
+    radius: f32,
+    terrain: usize,
+    speed_gen: f32,
+    vision_gen: f32,
+    resistance_gen: f32,
+}
+
+impl Editor {
+    pub fn new() -> Self {
+        Editor { active: false, tool: Tool::Terrain, radius: 3.0, terrain: 0, speed_gen: 0.17, vision_gen: 15.0, resistance_gen: 0.1 }
+    }
+
+    fn terrain(&self, world: &World) -> Terrain {
+        let terrains: Vec<Terrain> = world.biomes().terrains().collect();
+        terrains[self.terrain % terrains.len()]
+    }
+
+    /// Клавіші інструментів і клік по карті; повертає текст для рядка статусу, якщо щось сталося.
+    pub fn handle_input(&mut self, world: &mut World, cam: &Camera, mouse_free: bool) -> Option<String> {
+        for (tool, key) in Tool::ALL.into_iter().zip(Tool::KEYS) {
+            if is_key_pressed(key) { self.tool = tool; }
+        }
+        if is_key_pressed(KeyCode::LeftBracket) { self.radius = (self.radius - 1.0).max(0.0); }
+        if is_key_pressed(KeyCode::RightBracket) { self.radius = (self.radius + 1.0).min(40.0); }
+        if is_key_pressed(KeyCode::T) { self.terrain = (self.terrain + 1) % world.biomes().terrains().count(); }
+        // Гени нового Валкарая: стрілки вгору/вниз — швидкість, вліво/вправо — зір, PgUp/PgDn — опір
+        if is_key_pressed(KeyCode::Up) { self.speed_gen = (self.speed_gen + 0.01).min(0.3); }
+        if is_key_pressed(KeyCode::Down) { self.speed_gen = (self.speed_gen - 0.01).max(0.08); }
+        if is_key_pressed(KeyCode::Right) { self.vision_gen = (self.vision_gen + 1.0).min(30.0); }
+        if is_key_pressed(KeyCode::Left) { self.vision_gen = (self.vision_gen - 1.0).max(8.0); }
+        if is_key_pressed(KeyCode::PageUp) { self.resistance_gen = (self.resistance_gen + 0.05).min(1.0); }
+        if is_key_pressed(KeyCode::PageDown) { self.resistance_gen = (self.resistance_gen - 0.05).max(0.0); }
+
+        if !mouse_free { return None; }
+        let fire = if self.tool.is_brush() { is_mouse_button_down(MouseButton::Left) } else { is_mouse_button_pressed(MouseButton::Left) };
+        if !fire { return None; }
+        let p = cam.screen_to_world(Vec2::from(mouse_position()));
+        let r = self.radius;
+        match self.tool {
+            Tool::Terrain => { let t = self.terrain(world); world.paint_terrain(p, r, t); }
+            Tool::AddFood => world.add_food(p, r, 20.0),
+            Tool::ClearFood => world.clear_food(p, r),
+            Tool::Eraser => { world.erase_agents(p, r.max(0.5)); }
+            Tool::Valkarai => {
+                return Some(match world.spawn_valkarai(p, self.speed_gen, self.vision_gen, self.resistance_gen) {
+                    Some(id) => format!("Spawned Valkarai #{}", id),
+                    None => "Cannot spawn here".to_owned(),
+                });
+            }
+            Tool::Zombie => {
+                return Some(match world.spawn_zombie(p) {
+                    Some(id) => format!("Spawned zombie #{}", id),
+                    None => "Cannot spawn here".to_owned(),
+                });
+            }
+        }
+        None
+    }
+
+    /// Обрис пензля під курсором і панель з поточним інструментом.
+    pub fn draw(&self, world: &World, cam: &Camera) {
+        let m = Vec2::from(mouse_position());
+        if self.tool.is_brush() {
+            draw_circle_lines(m.x, m.y, self.radius.max(0.5) * cam.zoom, 1.5, YELLOW);
+        } else {
+            draw_circle_lines(m.x, m.y, 6.0, 2.0, YELLOW);
+        }
+
+        let (x, y, w) = (20.0, 80.0, 420.0);
+        draw_rectangle(x, y, w, 174.0, Color::new(0.0, 0.0, 0.0, 0.85));
+        draw_rectangle_lines(x, y, w, 174.0, 2.0, YELLOW);
+        draw_text("EDIT MODE (E to leave)", x + 10.0, y + 26.0, 24.0, YELLOW);
+        let lines = [
+            format!("Tool [1-6]: {}", self.tool.name()),
+            format!("Radius [ ]: {:.0}", self.radius),
+            format!("Terrain (T): {}", self.terrain(world).name()),
+            format!("New Valkarai: speed {:.2} (up/down), vision {:.0} (left/right)", self.speed_gen, self.vision_gen),
+            format!("              resistance {:.2} (PgUp/PgDn)", self.resistance_gen),
+        ];
+        for (i, line) in lines.iter().enumerate() {
+            draw_text(line, x + 10.0, y + 54.0 + i as f32 * 24.0, 18.0, WHITE);
+        }
+    }
+}
@@ -0,0 +1,79 @@
+use macroquad::prelude::*;
+use quadrisrah_sim::{Agent, AgentKind, AgentState};
+
+use super::camera::Camera;
+
+const PANEL_WIDTH: f32 = 340.0;
+const LINE: f32 = 24.0;
+
+fn panel_rect() -> Rect {
+    Rect::new(screen_width() - PANEL_WIDTH - 20.0, 80.0, PANEL_WIDTH, 21.0 * LINE + 30.0)
+}
+
+/// Чи курсор над панеллю — тоді клік не змінює вибір.
+pub fn contains_mouse() -> bool {
+    panel_rect().contains(Vec2::from(mouse_position()))
+}
+
+/// Радіус зору і лінія до поточної цілі вибраного агента, поверх карти.
+pub fn draw_overlay(agent: &Agent, cam: &Camera) {
+    let s = cam.world_to_screen(agent.pos);
+    draw_circle_lines(s.x, s.y, agent.vision_gen * cam.zoom, 1.5, Color::new(1.0, 1.0, 1.0, 0.6));
+    // Прокладений шлях, від агента до цілі
+    let mut prev = s;
+    for &(x, y) in agent.path.iter().rev() {
+        let p = cam.world_to_screen(vec2(x as f32 + 0.5, y as f32 + 0.5));
+        draw_line(prev.x, prev.y, p.x, p.y, 1.0, Color::new(1.0, 1.0, 0.4, 0.7));
+        prev = p;
+    }
+    if let Some(t) = agent.target {
+        let color = match agent.state {
+            AgentState::Fleeing => RED,
+            AgentState::SeekingMate => PINK,
+            AgentState::SeekingFood => GREEN,
+            AgentState::Chasing => PURPLE,
+            AgentState::Wandering => GRAY,
+        };
+        let ts = cam.world_to_screen(t);
+        draw_line(s.x, s.y, ts.x, ts.y, 2.0, color);
+        draw_circle_lines(ts.x, ts.y, 6.0, 2.0, color);
+    }
+}
+
+/// Панель з усіма полями `Agent` і його поточною поведінкою.
+pub fn draw_panel(agent: &Agent) {
+    let r = panel_rect();
+    draw_rectangle(r.x, r.y, r.w, r.h, Color::new(0.0, 0.0, 0.0, 0.85));
+    draw_rectangle_lines(r.x, r.y, r.w, r.h, 2.0, GRAY);
+    let (kind_str, kind_col) = match agent.kind {
+        AgentKind::Zombie => ("ZOMBIE", PURPLE),
+        AgentKind::Valkarai => ("VALKARAI", RED),
+    };
+    let (x, mut y) = (r.x + 15.0, r.y + 30.0);
+    draw_text(&format!("#{} {}", agent.id, kind_str), x, y, 28.0, kind_col);
+    y += LINE + 6.0;
+    let parents = match agent.parents { Some((a, b)) => format!("#{} + #{}", a, b), None => "founder".to_owned() };
+    let rows = [
+        ("state", agent.state.name().to_owned()),
+        ("position", format!("{:.1}, {:.1}", agent.pos.x, agent.pos.y)),
+        ("energy", format!("{:.1}", agent.energy)),
+        ("health", format!("{:.1} / {:.1}", agent.health, agent.max_health)),
+        ("damage", format!("{:.2}", agent.damage)),
+        ("speed_gen", format!("{:.3}", agent.speed_gen)),
+        ("vision_gen", format!("{:.2}", agent.vision_gen)),
+        ("resistance", format!("{:.2} (exposed {} ticks)", agent.resistance_gen, agent.exposure)),
+        ("cooldown", format!("{:.0}", agent.reproduce_cooldown)),
+        ("generation", agent.generation.to_string()),
+        ("parents", parents),
+        ("born", format!("tick {}", agent.birth_tick)),
+        ("age", format!("{} / {:.0}{}", agent.age, agent.lifespan_gen, if agent.is_fertile() { " fertile" } else { "" })),
+        ("target", agent.target.map_or("-".to_owned(), |t| format!("{:.1}, {:.1}", t.x, t.y))),
+    ];
+    for (label, value) in rows {
+        draw_text(label, x, y, 20.0, GRAY);
+        draw_text(&value, x + 120.0, y, 20.0, WHITE);
+        y += LINE;
+    }
+    y += LINE / 2.0;
+    draw_text("F: follow   click empty map: close", x, y, 18.0, DARKGRAY);
+}
@@ -0,0 +1,221 @@
+use macroquad::prelude::*;
+use quadrisrah_sim::{vegetation, AgentKind, StatsRecorder, Terrain, World, MAP_SCALE};
+
+mod camera;
+mod charts;
+mod clock;
+mod editor;
+mod inspector;
+mod overlay;
+mod registry;
+
+use camera::Camera;
+use clock::SimClock;
+use editor::Editor;
+use overlay::Layer;
+use registry::Registry;
+
+/// Куди F5 зберігає знімок і звідки F9 його читає.
+const SNAPSHOT_PATH: &str = "quadrisrah_snapshot.json";
+/// Куди F6 пише статистику, якщо не задано `--stats`; тоді в ній лише останні `charts::HISTORY` тіків.
+const STATS_PATH: &str = "quadrisrah_stats.csv";
+/// Куди F7 пише родовід.
+const LINEAGE_PATH: &str = "quadrisrah_lineage.graphml";
+/// Куди F8 пише карту світу.
+const MAP_PATH: &str = "quadrisrah_map.png";
+
+pub fn window_conf() -> Conf {
+    Conf {
+        window_title: "Quadrisrah: Entity Registry".to_owned(),
+        fullscreen: true,
+        ..Default::default()
+    }
+}
+
+fn terrain_color(world: &World, t: Terrain) -> Color {
+    let [r, g, b] = world.biomes().get(t).color;
+    Color::new(r, g, b, 1.0)
+}
+
+fn draw_world(world: &World, cam: &Camera, selected: Option<u64>, layer: Layer) {
+    let (xs, ys) = cam.visible_cells(world.size());
+    let cell = cam.zoom;
+    for x in xs {
+        for y in ys.clone() {
+            let c = &world.cells()[x][y];
+            let s = cam.world_to_screen(vec2(x as f32, y as f32));
+            // Вища клітинка світліша, щоб було видно хребти й узбережжя
+            let base = terrain_color(world, c.terrain);
+            let shade = 0.85 + 0.15 * c.elevation;
+            draw_rectangle(s.x, s.y, cell, cell, Color::new(base.r * shade, base.g * shade, base.b * shade, 1.0));
+            // Рослини: чим ближче до ємності біому, тим густіший фіолетовий
+            let fill = vegetation::fill(c, world.biomes().get(c.terrain));
+            if fill > 0.0 && layer != Layer::Food { draw_rectangle(s.x, s.y, cell, cell, Color::new(0.6, 0.1, 0.8, 0.7 * fill)); }
+        }
+    }
+    overlay::draw(world, cam, layer);
+    let threshold = world.config().reproduction_threshold;
+    for agent in world.agents() {
+        let s = cam.world_to_screen(agent.pos);
+        let r = (agent.vision_gen / 15.0) * cell * 0.7;
+        if !cam.is_visible(s, r) { continue; }
+        let color = if agent.kind == AgentKind::Zombie { BLACK } 
+                    else if agent.energy > threshold && agent.reproduce_cooldown == 0.0 { ORANGE } 
+                    else { RED };
+        draw_circle(s.x, s.y, r, color);
+        if selected == Some(agent.id) { draw_circle_lines(s.x, s.y, r + 3.0, 2.0, WHITE); }
+    }
+}
+
+/// Найближчий до курсора агент у межах кількох пікселів.
+fn agent_under_cursor(world: &World, cam: &Camera) -> Option<u64> {
+    let mouse = Vec2::from(mouse_position());
+    world.agents().iter()
+        .map(|a| (a.id, cam.world_to_screen(a.pos).distance(mouse)))
+        .filter(|&(_, d)| d < 12.0f32.max(cam.zoom))
+        .min_by(|a, b| a.1.total_cmp(&b.1))
+        .map(|(id, _)| id)
+}
+
+pub async fn run(mut world: World, stats_path: Option<String>) {
+    // Без --stats історія потрібна лише графікам; інакше на «max» вона росте на тисячі рядків за секунду
+    let mut stats = if stats_path.is_some() { StatsRecorder::new() } else { StatsRecorder::bounded(charts::HISTORY) };
+    let mut paused = false;
+    let mut registry = Registry::new();
+    let mut status: Option<(String, f64)> = None;
+    let mut cam = Camera::fit(world.size());
+    let mut selected: Option<u64> = None;
+    let mut show_registry = false;
+    let mut show_charts = false;
+    let mut clock = SimClock::new();
+    let mut layer = Layer::Off;
+    let mut editor = Editor::new();
+
+    loop {
+        clear_background(BLACK);
+
+        if is_key_pressed(KeyCode::Space) {
+            paused = !paused;
+            show_registry = paused && !editor.active;
+            registry.reset_scroll(); // Скидаємо скрол при вході/виході
+        }
+        // Tab ховає реєстр на паузі, щоб клацати агентів на карті
+        if paused && !editor.active && is_key_pressed(KeyCode::Tab) { show_registry = !show_registry; }
+        // C — графіки популяції, генів і їжі
+        if is_key_pressed(KeyCode::C) && !registry.typing() { show_charts = !show_charts; }
+        // H — теплові карти: їжа, щільність, голодні смерті, зараження
+        if is_key_pressed(KeyCode::H) && !registry.typing() { layer = layer.next(); }
+        // E — редактор світу; реєстр на цей час ховаємо, щоб мишу й стрілки мав редактор
+        if is_key_pressed(KeyCode::E) && !registry.typing() {
+            editor.active = !editor.active;
+            if editor.active { show_registry = false; }
+        }
+        // Схований реєстр не може лишитися в режимі набору, інакше C/H/E так і не оживуть
+        if !show_registry { registry.stop_typing(); }
+        // Esc під час набору лише закінчує набір, а не закриває програму
+        let typing = registry.typing();
+
+        if is_key_pressed(KeyCode::F5) {
+            let msg = match world.save(SNAPSHOT_PATH) {
+                Ok(()) => format!("Saved tick {} to {}", world.tick(), SNAPSHOT_PATH),
+                Err(e) => format!("Save failed: {}", e),
+            };
+            status = Some((msg, get_time()));
+        }
+        if is_key_pressed(KeyCode::F9) {
+            let msg = match World::load(SNAPSHOT_PATH) {
+                Ok(w) => {
+                    // Статистика й ID вибраного та того, за ким стежить нова камера, належать старому світу
+                    world = w;
+                    cam = Camera::fit(world.size());
+                    stats.clear();
+                    selected = None;
+                    format!("Loaded tick {} from {}", world.tick(), SNAPSHOT_PATH)
+                }
+                Err(e) => format!("Load failed: {}", e),
+            };
+            status = Some((msg, get_time()));
+        }
+
+        if is_key_pressed(KeyCode::F6) {
+            let path = stats_path.as_deref().unwrap_or(STATS_PATH);
+            let msg = match stats.save_csv(path) {
+                Ok(()) => format!("Wrote {} ticks of stats to {}", stats.rows().len(), path),
+                Err(e) => format!("Stats export failed: {}", e),
+            };
+            status = Some((msg, get_time()));
+        }
+
+        if is_key_pressed(KeyCode::F7) {
+            let msg = match world.lineage().save_graphml(LINEAGE_PATH) {
+                Ok(()) => format!("Wrote lineage of {} agents to {}", world.lineage().records().len(), LINEAGE_PATH),
+                Err(e) => format!("Lineage export failed: {}", e),
+            };
+            status = Some((msg, get_time()));
+        }
+
+        if is_key_pressed(KeyCode::F8) {
+            let msg = match world.save_map_png(MAP_PATH, MAP_SCALE) {
+                Ok(()) => format!("Wrote map of tick {} to {}", world.tick(), MAP_PATH),
+                Err(e) => format!("Map export failed: {}", e),
+            };
+            status = Some((msg, get_time()));
+        }
+
+        // -/= — повільніше/швидше, . — один тік на паузі
+        if is_key_pressed(KeyCode::Minus) { clock.slower(); }
+        if is_key_pressed(KeyCode::Equal) { clock.faster(); }
+        if paused {
+            clock.hold();
+            if is_key_pressed(KeyCode::Period) { SimClock::step(&mut world, &mut stats); }
+        } else {
+            clock.advance(&mut world, &mut stats, get_frame_time() as f64);
+        }
+
+        // Камера: ЛКМ — вибрати агента, F — стежити за вибраним. Поки відкрито реєстр, мишу забирає він.
+        if show_registry {
+            if let Some(id) = registry.handle_input(&world) {
+                selected = Some(id);
+                if let Some(a) = world.agent_by_id(id) { cam.look_at(a.pos, Registry::free_area_center()); }
+            }
+        } else {
+            cam.handle_input(world.size());
+        }
+        let over_ui = (show_registry && registry.contains_mouse()) || (show_charts && charts::contains_mouse()) || (selected.is_some() && inspector::contains_mouse());
+        if editor.active {
+            if let Some(msg) = editor.handle_input(&mut world, &cam, !over_ui) { status = Some((msg, get_time())); }
+        } else if is_mouse_button_pressed(MouseButton::Left) && !over_ui {
+            selected = agent_under_cursor(&world, &cam);
+        }
+        if is_key_pressed(KeyCode::F) { cam.follow = if cam.follow.is_some() { None } else { selected }; }
+        cam.track(&world);
+        if selected.is_some_and(|id| world.agent_by_id(id).is_none()) { selected = None; }
+
+        draw_world(&world, &cam, selected, layer);
+        let selected_agent = selected.and_then(|id| world.agent_by_id(id));
+        if let Some(agent) = selected_agent { inspector::draw_overlay(agent, &cam); }
+
+        // --- UI ЕЛЕМЕНТИ ---
+        let v_count = world.count(AgentKind::Valkarai);
+        let z_count = world.count(AgentKind::Zombie);
+        draw_text(&format!("Valkarai: {} | Zombies: {} | Seed: {} | Tick: {} | Speed: {}{}", v_count, z_count, world.seed(), world.tick(), clock.label(), if paused { " (paused, . to step)" } else { "" }), 20.0, 30.0, 30.0, DARKGREEN);
+        if let Some(id) = cam.follow { draw_text(&format!("Following #{} (F to stop)", id), 20.0, screen_height() - 20.0, 22.0, WHITE); }
+        
+        if let Some((msg, at)) = &status {
+            if get_time() - at < 4.0 { draw_text(msg, 20.0, 60.0, 24.0, YELLOW); } else { status = None; }
+        }
+
+        if editor.active { editor.draw(&world, &cam); }
+        if show_charts { charts::draw(&stats, &world); }
+        if show_registry { registry.draw(&world, selected); }
+        if let Some(agent) = selected_agent { inspector::draw_panel(agent); }
+
+        if is_key_pressed(KeyCode::Escape) && !typing {
+            if let Some(path) = &stats_path {
+                if let Err(e) = stats.save_csv(path) { eprintln!("error: {}: {}", path, e); }
+            }
+            break;
+        }
+        next_frame().await
+    }
+}
\ No newline at end of file
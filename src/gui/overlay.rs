@@ -0,0 +1,98 @@
+use macroquad::prelude::*;
+use quadrisrah_sim::World;
+
+use super::camera::Camera;
+
+/// Теплова карта поверх рельєфу; H перемикає по колу.
+#[derive(Clone, Copy, PartialEq)]
+pub enum Layer { Off, Food, Density, Starvation, Infections }
+
+impl Layer {
+    pub fn next(self) -> Self {
+        match self {
+            Layer::Off => Layer::Food, Layer::Food => Layer::Density, Layer::Density => Layer::Starvation,
+            Layer::Starvation => Layer::Infections, Layer::Infections => Layer::Off,
+        }
+    }
+
+    fn title(self) -> &'static str {
+        match self {
+            Layer::Off => "", Layer::Food => "Food per cell", Layer::Density => "Agents per block",
+            Layer::Starvation => "Starvation deaths per block", Layer::Infections => "Infections per block",
+        }
+    }
+
+    /// Сторона блоку в клітинках: рідкісні події збираємо крупніше, інакше карта — окремі точки.
+    fn block(self) -> usize {
+        match self { Layer::Food | Layer::Off => 1, _ => 5 }
+    }
+}
+
+/// Синій → жовтий → червоний; `t` у [0, 1].
+fn heat(t: f32) -> Color {
+    let t = t.clamp(0.0, 1.0);
+    let (r, g, b) = if t < 0.5 { let k = t * 2.0; (k, k, 1.0 - k) } else { (1.0, 2.0 - t * 2.0, 0.0) };
+    Color::new(r, g, b, 0.25 + 0.5 * t)
+}
+
+/// Значення шару по блоках, `[bx][by]`.
+fn values(world: &World, layer: Layer) -> Vec<Vec<f32>> {
+    let size = world.size();
+    let block = layer.block();
+    let n = size.div_ceil(block);
+    let mut v = vec![vec![0.0f32; n]; n];
+    match layer {
+        Layer::Off => {}
+        Layer::Density => {
+            for a in world.agents() { v[a.pos.x as usize / block][a.pos.y as usize / block] += 1.0; }
+        }
+        _ => {
+            for (x, col) in world.cells().iter().enumerate() {
+                for (y, c) in col.iter().enumerate() {
+                    v[x / block][y / block] += match layer {
+                        Layer::Food => c.food_level,
+                        Layer::Starvation => c.starvation_deaths as f32,
+                        _ => c.infections as f32,
+                    };
+                }
+            }
+        }
+    }
+    v
+}
+
+/// Малює шар поверх карти разом з легендою.
+pub fn draw(world: &World, cam: &Camera, layer: Layer) {
+    if layer == Layer::Off { return; }
+    let block = layer.block();
+    let v = values(world, layer);
+    let max = v.iter().flatten().fold(0.0f32, |m, &x| m.max(x));
+    let (xs, ys) = cam.visible_cells(world.size());
+    let side = block as f32 * cam.zoom;
+    let (bxs, bys) = (xs.start / block..xs.end.div_ceil(block), ys.start / block..ys.end.div_ceil(block));
+    for (bx, col) in v.iter().enumerate().take(bxs.end).skip(bxs.start) {
+        for (by, &val) in col.iter().enumerate().take(bys.end).skip(bys.start) {
+            if val <= 0.0 { continue; }
+            let s = cam.world_to_screen(vec2((bx * block) as f32, (by * block) as f32));
+            draw_rectangle(s.x, s.y, side, side, heat(val / max));
+        }
+    }
+    draw_legend(layer, max);
+}
+
+fn draw_legend(layer: Layer, max: f32) {
+    let (w, h) = (260.0, 16.0);
+    let (x, y) = (screen_width() - w - 40.0, screen_height() - 60.0);
+    draw_rectangle(x - 10.0, y - 34.0, w + 20.0, 70.0, Color::new(0.0, 0.0, 0.0, 0.85));
+    draw_text(&format!("{} (H to switch)", layer.title()), x, y - 12.0, 18.0, WHITE);
+    let steps = 52;
+    let sw = w / steps as f32;
+    for i in 0..steps {
+        let mut c = heat(i as f32 / (steps - 1) as f32);
+        c.a = 1.0;
+        draw_rectangle(x + i as f32 * sw, y, sw + 0.5, h, c);
+    }
+    draw_text("0", x, y + h + 14.0, 16.0, GRAY);
+    let hi = format!("{:.0}", max);
+    draw_text(&hi, x + w - measure_text(&hi, None, 16, 1.0).width, y + h + 14.0, 16.0, GRAY);
+}
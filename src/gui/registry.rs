@@ -0,0 +1,208 @@
+use macroquad::prelude::*;
+use quadrisrah_sim::{Agent, AgentKind, World};
+use std::cmp::Ordering;
+
+const ROW_H: f32 = 26.0;
+const HEADER_Y: f32 = 190.0;
+
+#[derive(Clone, Copy, PartialEq)]
+enum Column { Id, Kind, Speed, Vision, Resist, Energy, Health, Age }
+
+impl Column {
+    const ALL: [Column; 8] = [Column::Id, Column::Kind, Column::Speed, Column::Vision, Column::Resist, Column::Energy, Column::Health, Column::Age];
+
+    fn title(self) -> &'static str {
+        match self {
+            Column::Id => "ID", Column::Kind => "TYPE", Column::Speed => "SPEED", Column::Vision => "VISION", Column::Resist => "RESIST",
+            Column::Energy => "ENERGY", Column::Health => "HEALTH", Column::Age => "AGE",
+        }
+    }
+
+    /// Зсув колонки від лівого краю панелі.
+    fn x(self) -> f32 {
+        match self {
+            Column::Id => 20.0, Column::Kind => 110.0, Column::Speed => 240.0, Column::Vision => 330.0,
+            Column::Resist => 420.0, Column::Energy => 510.0, Column::Health => 610.0, Column::Age => 730.0,
+        }
+    }
+
+    fn cmp(self, a: &Agent, b: &Agent) -> Ordering {
+        match self {
+            Column::Id => a.id.cmp(&b.id),
+            Column::Kind => (a.kind as u8).cmp(&(b.kind as u8)),
+            Column::Speed => a.speed_gen.total_cmp(&b.speed_gen),
+            Column::Vision => a.vision_gen.total_cmp(&b.vision_gen),
+            Column::Resist => a.resistance_gen.total_cmp(&b.resistance_gen),
+            Column::Energy => a.energy.total_cmp(&b.energy),
+            Column::Health => a.health.total_cmp(&b.health),
+            Column::Age => a.age.cmp(&b.age),
+        }
+    }
+}
+
+/// Таблиця агентів на паузі: сортування кліком по заголовку, фільтр за видом (K) і за ID (/).
+pub struct Registry {
+    sort: Column,
+    descending: bool,
+    kind_filter: Option<AgentKind>,
+    id_filter: String,
+    typing_id: bool,
+    scroll: usize,
+}
+
+fn panel_rect() -> Rect {
+    Rect::new(50.0, 50.0, 850.0f32.min(screen_width() - 100.0), screen_height() - 100.0)
+}
+
+impl Registry {
+    pub fn new() -> Self {
+        Registry { sort: Column::Id, descending: false, kind_filter: None, id_filter: String::new(), typing_id: false, scroll: 0 }
+    }
+
+    /// Скидає прокрутку, коли реєстр відкривають знову.
+    pub fn reset_scroll(&mut self) { self.scroll = 0; }
+
+    /// Чи зараз набирають фільтр ID — тоді літерні гарячі клавіші не діють.
+    pub fn typing(&self) -> bool { self.typing_id }
+
+    /// Закінчує набір фільтра ID, напр. коли реєстр сховали посеред набору.
+    pub fn stop_typing(&mut self) { self.typing_id = false; }
+
+    /// Чи курсор над панеллю.
+    pub fn contains_mouse(&self) -> bool {
+        panel_rect().contains(Vec2::from(mouse_position()))
+    }
+
+    /// Права частина екрана, не закрита реєстром, — туди камера ставить вибраного агента.
+    pub fn free_area_center() -> Vec2 {
+        let r = panel_rect();
+        vec2((r.right() + screen_width()) / 2.0, screen_height() / 2.0)
+    }
+
+    fn rows_per_page() -> usize {
+        ((panel_rect().bottom() - HEADER_Y - 90.0) / ROW_H).max(1.0) as usize
+    }
+
+    /// Відфільтровані й відсортовані агенти.
+    fn rows<'a>(&self, world: &'a World) -> Vec<&'a Agent> {
+        let mut rows: Vec<&Agent> = world.agents().iter()
+            .filter(|a| self.kind_filter.is_none_or(|k| a.kind == k))
+            .filter(|a| self.id_filter.is_empty() || a.id.to_string().contains(&self.id_filter))
+            .collect();
+        rows.sort_by(|a, b| {
+            let o = self.sort.cmp(a, b).then(a.id.cmp(&b.id));
+            if self.descending { o.reverse() } else { o }
+        });
+        rows
+    }
+
+    /// Обробляє клавіатуру й мишу; повертає `id` агента, якщо клацнули його рядок.
+    pub fn handle_input(&mut self, world: &World) -> Option<u64> {
+        while let Some(c) = get_char_pressed() {
+            if self.typing_id && c.is_ascii_digit() && self.id_filter.len() < 12 { self.id_filter.push(c); }
+        }
+        if self.typing_id {
+            if is_key_pressed(KeyCode::Backspace) { self.id_filter.pop(); self.scroll = 0; }
+            if is_key_pressed(KeyCode::Enter) || is_key_pressed(KeyCode::Escape) { self.typing_id = false; }
+        } else {
+            if is_key_pressed(KeyCode::Slash) { self.typing_id = true; }
+            if is_key_pressed(KeyCode::K) {
+                self.kind_filter = match self.kind_filter {
+                    None => Some(AgentKind::Valkarai),
+                    Some(AgentKind::Valkarai) => Some(AgentKind::Zombie),
+                    Some(AgentKind::Zombie) => None,
+                };
+                self.scroll = 0;
+            }
+        }
+
+        let total = self.rows(world).len();
+        let page = Self::rows_per_page();
+        let max_scroll = total.saturating_sub(page);
+        if is_key_pressed(KeyCode::Down) { self.scroll += 1; }
+        if is_key_pressed(KeyCode::Up) { self.scroll = self.scroll.saturating_sub(1); }
+        if is_key_pressed(KeyCode::PageDown) { self.scroll += page; }
+        if is_key_pressed(KeyCode::PageUp) { self.scroll = self.scroll.saturating_sub(page); }
+        let wheel = mouse_wheel().1;
+        if wheel < 0.0 { self.scroll += 3; }
+        if wheel > 0.0 { self.scroll = self.scroll.saturating_sub(3); }
+        self.scroll = self.scroll.min(max_scroll);
+
+        if !is_mouse_button_pressed(MouseButton::Left) || !self.contains_mouse() { return None; }
+        let (mx, my) = mouse_position();
+        let r = panel_rect();
+        if (HEADER_Y - 20.0..HEADER_Y + 6.0).contains(&my) {
+            let col = Column::ALL.iter().rev().find(|c| mx >= r.x + c.x()).copied();
+            if let Some(col) = col {
+                if col == self.sort { self.descending = !self.descending; } else { self.sort = col; self.descending = false; }
+            }
+            return None;
+        }
+        if (HEADER_Y - 60.0..HEADER_Y - 35.0).contains(&my) && mx >= r.x + 330.0 {
+            self.typing_id = true;
+            return None;
+        }
+        let first_row_top = HEADER_Y + 10.0;
+        if my >= first_row_top {
+            let idx = self.scroll + ((my - first_row_top) / ROW_H) as usize;
+            return self.rows(world).get(idx).map(|a| a.id).filter(|_| idx < self.scroll + page);
+        }
+        None
+    }
+
+    pub fn draw(&self, world: &World, selected: Option<u64>) {
+        let r = panel_rect();
+        // Напівпрозоре меню
+        draw_rectangle(r.x, r.y, r.w, r.h, Color::new(0.0, 0.0, 0.0, 0.85));
+        draw_text("ENTITY REGISTRY (PAUSED)", r.x + 20.0, r.y + 40.0, 40.0, YELLOW);
+        draw_text("Click a header to sort, a row to select. Wheel/arrows scroll, TAB hides", r.x + 20.0, r.y + 70.0, 18.0, GRAY);
+
+        let kind_str = match self.kind_filter { None => "ALL", Some(AgentKind::Valkarai) => "VALKARAI", Some(AgentKind::Zombie) => "ZOMBIE" };
+        let filter_y = HEADER_Y - 40.0;
+        draw_text(&format!("Kind (K): {}", kind_str), r.x + 20.0, filter_y, 22.0, WHITE);
+        let cursor = if self.typing_id { "_" } else { "" };
+        let id_col = if self.typing_id { YELLOW } else { WHITE };
+        draw_text(&format!("ID contains (/): {}{}", self.id_filter, cursor), r.x + 330.0, filter_y, 22.0, id_col);
+
+        // Заголовки таблиці
+        for col in Column::ALL {
+            let arrow = if col == self.sort { if self.descending { " v" } else { " ^" } } else { "" };
+            let color = if col == self.sort { YELLOW } else { WHITE };
+            draw_text(&format!("{}{}", col.title(), arrow), r.x + col.x(), HEADER_Y, 22.0, color);
+        }
+        draw_line(r.x + 20.0, HEADER_Y + 5.0, r.right() - 20.0, HEADER_Y + 5.0, 2.0, GRAY);
+
+        // Список істот
+        let rows = self.rows(world);
+        for (i, agent) in rows.iter().skip(self.scroll).take(Self::rows_per_page()).enumerate() {
+            let y = HEADER_Y + 10.0 + (i as f32 + 1.0) * ROW_H - 6.0;
+            if selected == Some(agent.id) {
+                draw_rectangle(r.x + 10.0, y - ROW_H + 8.0, r.w - 20.0, ROW_H, Color::new(1.0, 1.0, 1.0, 0.15));
+            }
+            let (kind_str, kind_col) = if agent.kind == AgentKind::Zombie { ("ZOMBIE", PURPLE) } else { ("VALKARAI", RED) };
+            draw_text(&format!("{:04}", agent.id), r.x + Column::Id.x(), y, 20.0, GRAY);
+            draw_text(kind_str, r.x + Column::Kind.x(), y, 20.0, kind_col);
+            draw_text(&format!("{:.2}", agent.speed_gen), r.x + Column::Speed.x(), y, 20.0, WHITE);
+            draw_text(&format!("{:.1}", agent.vision_gen), r.x + Column::Vision.x(), y, 20.0, WHITE);
+            draw_text(&format!("{:.2}", agent.resistance_gen), r.x + Column::Resist.x(), y, 20.0, WHITE);
+            draw_text(&format!("{:.0}", agent.energy), r.x + Column::Energy.x(), y, 20.0, GREEN);
+            draw_text(&format!("{:.0}/{:.0}", agent.health, agent.max_health), r.x + Column::Health.x(), y, 20.0, WHITE);
+            draw_text(&format!("{}", agent.age), r.x + Column::Age.x(), y, 20.0, WHITE);
+        }
+
+        // Підсумки по відфільтрованих
+        let n = rows.len();
+        let avg = |f: &dyn Fn(&Agent) -> f32| if n == 0 { 0.0 } else { rows.iter().map(|a| f(a)).sum::<f32>() / n as f32 };
+        let summary = format!(
+            "{} agents | avg speed {:.3} | vision {:.1} | resist {:.2} | energy {:.0} | health {:.0} | age {:.0}",
+            n, avg(&|a| a.speed_gen), avg(&|a| a.vision_gen), avg(&|a| a.resistance_gen), avg(&|a| a.energy), avg(&|a| a.health),
+            avg(&|a| a.age as f32),
+        );
+        draw_line(r.x + 20.0, r.bottom() - 50.0, r.right() - 20.0, r.bottom() - 50.0, 1.0, GRAY);
+        draw_text(&summary, r.x + 20.0, r.bottom() - 25.0, 20.0, YELLOW);
+        if n > 0 {
+            let last = (self.scroll + Self::rows_per_page()).min(n);
+            draw_text(&format!("{}-{} of {}", self.scroll + 1, last, n), r.right() - 130.0, r.y + 40.0, 20.0, GRAY);
+        }
+    }
+}
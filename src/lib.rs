@@ -0,0 +1,26 @@
+//! Ядро симуляції Quadrisrah без жодної залежності від рендера.
+//!
+//! Фронтенд на macroquad живе в бінарнику (`src/main.rs`) і вмикається фічею `gui`.
+
+pub mod biome;
+mod climate;
+mod combat;
+pub mod config;
+mod edit;
+pub mod lineage;
+pub mod map_image;
+pub mod path;
+pub mod snapshot;
+pub mod spatial;
+pub mod stats;
+pub mod vegetation;
+pub mod world;
+
+pub use glam::{vec2, Vec2};
+pub use biome::{BiomeDef, BiomeSet, Passability};
+pub use config::{ConfigError, SimConfig};
+pub use lineage::{DeathCause, Lineage, LineageRecord};
+pub use map_image::{MapError, MAP_SCALE};
+pub use snapshot::{SnapshotError, SNAPSHOT_VERSION};
+pub use stats::{StatsRecorder, TickStats};
+pub use world::{Agent, AgentKind, AgentState, Cell, Terrain, TickEvents, World};
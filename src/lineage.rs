@@ -0,0 +1,137 @@
+use serde::{Deserialize, Serialize};
+use std::io::{self, Write};
+use std::path::Path;
+
+use crate::world::Agent;
+
+/// Чому агент покинув світ.
+#[derive(Clone, Copy, PartialEq, Debug, Serialize, Deserialize)]
+pub enum DeathCause {
+    Starvation,
+    Combat,
+    /// Валкарай дожив до `lifespan_gen`.
+    OldAge,
+    /// Зомбі, чиє здоров'я вичерпалось від розкладу.
+    Decay,
+    /// Прибраний гумкою редактора.
+    Removed,
+}
+
+impl DeathCause {
+    pub fn name(self) -> &'static str {
+        match self {
+            DeathCause::Starvation => "starvation", DeathCause::Combat => "combat", DeathCause::OldAge => "old_age",
+            DeathCause::Decay => "decay", DeathCause::Removed => "removed",
+        }
+    }
+}
+
+/// Запис про одного агента, що живе і після його смерті.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct LineageRecord {
+    pub id: u64,
+    pub parents: Option<(u64, u64)>,
+    pub generation: u32,
+    pub birth_tick: u64,
+    pub infected_tick: Option<u64>,
+    pub death: Option<(u64, DeathCause)>,
+    /// Гени на момент народження.
+    pub speed_gen: f32,
+    pub vision_gen: f32,
+    pub max_health: f32,
+    pub damage: f32,
+    pub lifespan_gen: f32,
+    pub resistance_gen: f32,
+}
+
+/// Родовід усіх агентів, які будь-коли жили. `records[id]` — запис агента з цим `id`.
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct Lineage {
+    records: Vec<LineageRecord>,
+}
+
+impl Lineage {
+    /// Ідентифікатор для наступного агента.
+    pub fn next_id(&self) -> u64 { self.records.len() as u64 }
+
+    pub(crate) fn record_birth(&mut self, agent: &Agent) {
+        debug_assert_eq!(agent.id, self.next_id());
+        self.records.push(LineageRecord {
+            id: agent.id,
+            parents: agent.parents,
+            generation: agent.generation,
+            birth_tick: agent.birth_tick,
+            infected_tick: None,
+            death: None,
+            speed_gen: agent.speed_gen,
+            vision_gen: agent.vision_gen,
+            max_health: agent.max_health,
+            damage: agent.damage,
+            lifespan_gen: agent.lifespan_gen,
+            resistance_gen: agent.resistance_gen,
+        });
+    }
+
+    pub(crate) fn record_infection(&mut self, id: u64, tick: u64) {
+        if let Some(r) = self.records.get_mut(id as usize) { r.infected_tick = Some(tick); }
+    }
+
+    pub(crate) fn record_death(&mut self, id: u64, tick: u64, cause: DeathCause) {
+        if let Some(r) = self.records.get_mut(id as usize) { r.death = Some((tick, cause)); }
+    }
+
+    pub fn get(&self, id: u64) -> Option<&LineageRecord> { self.records.get(id as usize) }
+
+    pub fn records(&self) -> &[LineageRecord] { &self.records }
+
+    /// Чи ідентифікатори йдуть підряд з нуля — перевіряється при завантаженні знімка.
+    pub(crate) fn is_consistent(&self) -> bool {
+        self.records.iter().enumerate().all(|(i, r)| r.id == i as u64)
+    }
+
+    /// Граф "батько -> дитина" у форматі GraphML, з генами та датами як атрибутами вузлів.
+    pub fn write_graphml(&self, mut out: impl Write) -> io::Result<()> {
+        writeln!(out, r#"<?xml version="1.0" encoding="UTF-8"?>"#)?;
+        writeln!(out, r#"<graphml xmlns="http://graphml.graphdrawing.org/xmlns">"#)?;
+        let keys = [
+            ("generation", "int"), ("birth_tick", "long"), ("infected_tick", "long"), ("death_tick", "long"),
+            ("death_cause", "string"), ("speed_gen", "float"), ("vision_gen", "float"), ("max_health", "float"), ("damage", "float"),
+            ("lifespan_gen", "float"), ("resistance_gen", "float"),
+        ];
+        for (name, ty) in keys {
+            writeln!(out, r#"  <key id="{0}" for="node" attr.name="{0}" attr.type="{1}"/>"#, name, ty)?;
+        }
+        writeln!(out, r#"  <graph id="lineage" edgedefault="directed">"#)?;
+        for r in &self.records {
+            writeln!(out, r#"    <node id="n{}">"#, r.id)?;
+            writeln!(out, r#"      <data key="generation">{}</data>"#, r.generation)?;
+            writeln!(out, r#"      <data key="birth_tick">{}</data>"#, r.birth_tick)?;
+            if let Some(t) = r.infected_tick { writeln!(out, r#"      <data key="infected_tick">{}</data>"#, t)?; }
+            if let Some((t, cause)) = r.death {
+                writeln!(out, r#"      <data key="death_tick">{}</data>"#, t)?;
+                writeln!(out, r#"      <data key="death_cause">{}</data>"#, cause.name())?;
+            }
+            writeln!(out, r#"      <data key="speed_gen">{}</data>"#, r.speed_gen)?;
+            writeln!(out, r#"      <data key="vision_gen">{}</data>"#, r.vision_gen)?;
+            writeln!(out, r#"      <data key="max_health">{}</data>"#, r.max_health)?;
+            writeln!(out, r#"      <data key="damage">{}</data>"#, r.damage)?;
+            writeln!(out, r#"      <data key="lifespan_gen">{}</data>"#, r.lifespan_gen)?;
+            writeln!(out, r#"      <data key="resistance_gen">{}</data>"#, r.resistance_gen)?;
+            writeln!(out, "    </node>")?;
+        }
+        for r in &self.records {
+            if let Some((a, b)) = r.parents {
+                writeln!(out, r#"    <edge source="n{}" target="n{}"/>"#, a, r.id)?;
+                writeln!(out, r#"    <edge source="n{}" target="n{}"/>"#, b, r.id)?;
+            }
+        }
+        writeln!(out, "  </graph>")?;
+        writeln!(out, "</graphml>")
+    }
+
+    pub fn save_graphml(&self, path: impl AsRef<Path>) -> io::Result<()> {
+        let mut out = io::BufWriter::new(std::fs::File::create(path)?);
+        self.write_graphml(&mut out)?;
+        out.flush()
+    }
+}
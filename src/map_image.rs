@@ -0,0 +1,188 @@
+//! Карти світу у PNG: кожен піксель — одна клітинка, колір — біом.
+
+use ::rand::SeedableRng;
+use glam::vec2;
+use rand_chacha::ChaCha8Rng;
+use std::fmt;
+use std::fs::File;
+use std::io::{self, Write};
+use std::path::Path;
+
+use crate::config::{ConfigError, SimConfig};
+use crate::vegetation;
+use crate::world::{AgentKind, Cell, World};
+
+/// Найбільша відстань у RGB (0..255), на якій колір пікселя ще вважається кольором біому.
+pub const COLOR_TOLERANCE: f32 = 48.0;
+
+#[derive(Debug)]
+pub enum MapError {
+    Io(std::io::Error),
+    Decode(String),
+    NotSquare { width: u32, height: u32 },
+    SizeMismatch { terrain: u32, food: u32 },
+    UnknownColor { x: u32, y: u32, rgb: [u8; 3] },
+    Config(ConfigError),
+}
+
+impl fmt::Display for MapError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            MapError::Io(e) => write!(f, "cannot read map: {}", e),
+            MapError::Decode(e) => write!(f, "cannot decode map: {}", e),
+            MapError::NotSquare { width, height } => write!(f, "map must be square, got {}x{}", width, height),
+            MapError::SizeMismatch { terrain, food } => {
+                write!(f, "food map is {0}x{0} but terrain map is {1}x{1}", food, terrain)
+            }
+            MapError::UnknownColor { x, y, rgb: [r, g, b] } => {
+                write!(f, "pixel ({}, {}) has color #{:02x}{:02x}{:02x}, which matches no biome", x, y, r, g, b)
+            }
+            MapError::Config(e) => write!(f, "{}", e),
+        }
+    }
+}
+
+impl std::error::Error for MapError {}
+
+/// Квадратна картинка як рядки RGB-пікселів: `pixels[y * size + x]`.
+struct Image {
+    size: u32,
+    pixels: Vec<[u8; 3]>,
+}
+
+fn read_png(path: &Path) -> Result<Image, MapError> {
+    let mut decoder = png::Decoder::new(File::open(path).map_err(MapError::Io)?);
+    // Палітру розгортаємо, 16 біт зводимо до 8
+    decoder.set_transformations(png::Transformations::normalize_to_color8());
+    let mut reader = decoder.read_info().map_err(|e| MapError::Decode(e.to_string()))?;
+    let mut buf = vec![0; reader.output_buffer_size()];
+    let info = reader.next_frame(&mut buf).map_err(|e| MapError::Decode(e.to_string()))?;
+    if info.width != info.height { return Err(MapError::NotSquare { width: info.width, height: info.height }); }
+    let channels = info.color_type.samples();
+    let pixels = buf[..info.buffer_size()].chunks_exact(channels).map(|p| match channels {
+        1 | 2 => [p[0]; 3],
+        _ => [p[0], p[1], p[2]],
+    }).collect();
+    Ok(Image { size: info.width, pixels })
+}
+
+fn to_rgb8(color: [f32; 3]) -> [u8; 3] {
+    color.map(|c| (c * 255.0).round() as u8)
+}
+
+impl World {
+    /// Світ із карти рельєфу `terrain_png` і, за бажанням, карти їжі `food_png`: яскравість пікселя —
+    /// частка ємності клітинки (білий — повна). Без карти їжі рослини стартують як у `with_config`.
+    ///
+    /// Розмір світу береться з картинки, решта — з `config`. Колір пікселя зіставляється
+    /// з найближчим кольором біому набору в межах `COLOR_TOLERANCE`. Сирі поля клімату
+    /// клітинки ставляться в середину ніші її біому.
+    pub fn from_map_png(mut config: SimConfig, seed: u64, terrain_png: impl AsRef<Path>, food_png: Option<&Path>) -> Result<World, MapError> {
+        let image = read_png(terrain_png.as_ref())?;
+        config.grid_size = image.size as usize;
+        config.validate().map_err(MapError::Config)?;
+        let food = match food_png {
+            Some(path) => {
+                let food = read_png(path)?;
+                if food.size != image.size { return Err(MapError::SizeMismatch { terrain: image.size, food: food.size }); }
+                Some(food)
+            }
+            None => None,
+        };
+
+        let biomes = config.biome_set();
+        let palette: Vec<_> = biomes.biomes.iter().map(|b| (b, to_rgb8(b.color))).collect();
+        let size = image.size as usize;
+        let mut cells = Vec::with_capacity(size);
+        for x in 0..size {
+            let mut col = Vec::with_capacity(size);
+            for y in 0..size {
+                let rgb = image.pixels[y * size + x];
+                let dist = |c: [u8; 3]| -> f32 { (0..3).map(|i| (rgb[i] as f32 - c[i] as f32).powi(2)).sum::<f32>().sqrt() };
+                let (biome, d) = palette.iter().map(|&(b, c)| (b, dist(c))).min_by(|a, b| a.1.total_cmp(&b.1)).unwrap();
+                if d > COLOR_TOLERANCE { return Err(MapError::UnknownColor { x: x as u32, y: y as u32, rgb }); }
+                let food_level = food.as_ref().map_or(vegetation::initial_food(biome), |f| {
+                    let [r, g, b] = f.pixels[y * size + x];
+                    (0.299 * r as f32 + 0.587 * g as f32 + 0.114 * b as f32) / 255.0 * biome.capacity
+                });
+                let (elevation, temperature, moisture) = biome.niche_center();
+                col.push(Cell {
+                    terrain: biome.terrain, food_level, vigor: 1.0,
+                    elevation, temperature, moisture,
+                    starvation_deaths: 0, infections: 0,
+                });
+            }
+            cells.push(col);
+        }
+        Ok(World::populate(config, seed, ChaCha8Rng::seed_from_u64(seed), cells))
+    }
+}
+
+/// Пікселів на клітинку в знімку карти за замовчуванням.
+pub const MAP_SCALE: u32 = 4;
+
+const FOOD_COLOR: [f32; 3] = [0.6, 0.1, 0.8];
+const VALKARAI_COLOR: [u8; 3] = [230, 41, 55];
+const ZOMBIE_COLOR: [u8; 3] = [0, 0, 0];
+
+impl World {
+    /// Малює рельєф, їжу й агентів у RGB-буфер (рядок за рядком), `scale` пікселів на клітинку.
+    ///
+    /// Рельєф — чисті кольори біомів, тож порожня карта читається назад `from_map_png`.
+    /// Рослини підфарбовують клітинку тим сильніше, чим ближче вона до ємності біому.
+    /// Валкараї — червоні кружки, зомбі — чорні, поверх усього.
+    pub fn render_map(&self, scale: u32) -> (u32, Vec<u8>) {
+        let scale = scale.max(1) as usize;
+        let side = self.size() * scale;
+        let mut pixels = vec![0u8; side * side * 3];
+        for (x, col) in self.cells().iter().enumerate() {
+            for (y, cell) in col.iter().enumerate() {
+                let biome = self.biomes().get(cell.terrain);
+                let base = biome.color;
+                let t = 0.7 * vegetation::fill(cell, biome);
+                let rgb = to_rgb8([0, 1, 2].map(|i| base[i] + (FOOD_COLOR[i] - base[i]) * t));
+                for py in y * scale..(y + 1) * scale {
+                    for px in x * scale..(x + 1) * scale {
+                        pixels[(py * side + px) * 3..][..3].copy_from_slice(&rgb);
+                    }
+                }
+            }
+        }
+        // Кружок завжди зачіпає хоча б піксель, у якому стоїть агент
+        let r = (0.8 * scale as f32).max(0.75);
+        for kind in [AgentKind::Valkarai, AgentKind::Zombie] {
+            let rgb = if kind == AgentKind::Zombie { ZOMBIE_COLOR } else { VALKARAI_COLOR };
+            for a in self.agents_of(kind) {
+                let c = a.pos * scale as f32;
+                let lo = |v: f32| (v - r).floor().max(0.0) as usize;
+                let hi = |v: f32| ((v + r).ceil() as usize).min(side);
+                for py in lo(c.y)..hi(c.y) {
+                    for px in lo(c.x)..hi(c.x) {
+                        if c.distance(vec2(px as f32 + 0.5, py as f32 + 0.5)) <= r {
+                            pixels[(py * side + px) * 3..][..3].copy_from_slice(&rgb);
+                        }
+                    }
+                }
+            }
+        }
+        (side as u32, pixels)
+    }
+
+    /// Пише `render_map(scale)` як PNG.
+    pub fn write_map_png(&self, out: impl Write, scale: u32) -> io::Result<()> {
+        let (side, pixels) = self.render_map(scale);
+        let mut encoder = png::Encoder::new(out, side, side);
+        encoder.set_color(png::ColorType::Rgb);
+        encoder.set_depth(png::BitDepth::Eight);
+        let mut writer = encoder.write_header()?;
+        writer.write_image_data(&pixels)?;
+        writer.finish()?;
+        Ok(())
+    }
+
+    pub fn save_map_png(&self, path: impl AsRef<Path>, scale: u32) -> io::Result<()> {
+        let mut out = io::BufWriter::new(File::create(path)?);
+        self.write_map_png(&mut out, scale)?;
+        out.flush()
+    }
+}
@@ -0,0 +1,101 @@
+use std::cmp::Ordering;
+use std::collections::BinaryHeap;
+
+use crate::biome::{BiomeSet, Passability};
+use crate::world::Cell;
+
+/// На скільки клітинок пошук може відійти за прямокутник між стартом і ціллю;
+/// якщо шляху не знайшлося, пробуємо ще раз з учетверо ширшим полем.
+pub const PATH_MARGIN: usize = 10;
+
+const NEIGHBOURS: [(i32, i32); 8] = [(1, 0), (-1, 0), (0, 1), (0, -1), (1, 1), (1, -1), (-1, 1), (-1, -1)];
+
+#[derive(PartialEq)]
+struct Node { f: f32, idx: usize }
+
+impl Eq for Node {}
+
+impl Ord for Node {
+    // BinaryHeap — max-купа, тож порівнюємо навпаки; при рівних f менший індекс іде першим
+    fn cmp(&self, other: &Self) -> Ordering {
+        other.f.total_cmp(&self.f).then(other.idx.cmp(&self.idx))
+    }
+}
+
+impl PartialOrd for Node {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> { Some(self.cmp(other)) }
+}
+
+/// Ціна входу в клітинку: обернена до множника швидкості біому. `None` — туди не можна.
+fn step_cost(cells: &[Vec<Cell>], biomes: &BiomeSet, x: usize, y: usize, dire: bool) -> Option<f32> {
+    let b = biomes.get(cells[x][y].terrain);
+    let passable = match b.passable {
+        Passability::Open => true,
+        Passability::Wall => false,
+        Passability::DireOnly => dire,
+    };
+    (passable && b.speed > 0.0).then(|| 1.0 / b.speed)
+}
+
+/// A* по клітинках `cells[x][y]` з восьми сусідами; `dire` відкриває біоми `DireOnly`.
+///
+/// Пошук обмежений прямокутником між `from` і `to` з полем `PATH_MARGIN` (а потім учетверо
+/// ширшим), тож дуже далекі обхідні шляхи не знаходяться. Діагональ не зрізає кут стіни.
+/// Повертає клітинки шляху без стартової, від цілі до першого кроку (зручно знімати
+/// з кінця через `pop`), або `None`, якщо ціль недосяжна.
+pub fn find_path(cells: &[Vec<Cell>], biomes: &BiomeSet, from: (usize, usize), to: (usize, usize), dire: bool) -> Option<Vec<(usize, usize)>> {
+    if from == to { return Some(Vec::new()); }
+    step_cost(cells, biomes, to.0, to.1, dire)?;
+    search(cells, biomes, from, to, dire, PATH_MARGIN).or_else(|| search(cells, biomes, from, to, dire, PATH_MARGIN * 4))
+}
+
+fn search(cells: &[Vec<Cell>], biomes: &BiomeSet, from: (usize, usize), to: (usize, usize), dire: bool, margin: usize) -> Option<Vec<(usize, usize)>> {
+    let size = cells.len();
+    let (x0, x1) = (from.0.min(to.0).saturating_sub(margin), (from.0.max(to.0) + margin).min(size - 1));
+    let (y0, y1) = (from.1.min(to.1).saturating_sub(margin), (from.1.max(to.1) + margin).min(size - 1));
+    let (w, h) = (x1 - x0 + 1, y1 - y0 + 1);
+    let idx = |x: usize, y: usize| (x - x0) * h + (y - y0);
+
+    // Найдешевший крок — допустима евристика для octile-відстані
+    let min_cost = biomes.biomes.iter().filter(|b| b.speed > 0.0).map(|b| 1.0 / b.speed).fold(f32::MAX, f32::min);
+    let heuristic = |x: usize, y: usize| {
+        let (dx, dy) = (x.abs_diff(to.0) as f32, y.abs_diff(to.1) as f32);
+        (dx.max(dy) + (std::f32::consts::SQRT_2 - 1.0) * dx.min(dy)) * min_cost
+    };
+
+    let mut g = vec![f32::INFINITY; w * h];
+    let mut parent = vec![usize::MAX; w * h];
+    let mut open = BinaryHeap::new();
+    g[idx(from.0, from.1)] = 0.0;
+    open.push(Node { f: heuristic(from.0, from.1), idx: idx(from.0, from.1) });
+
+    while let Some(Node { f, idx: cur }) = open.pop() {
+        let (cx, cy) = (x0 + cur / h, y0 + cur % h);
+        if (cx, cy) == to {
+            let mut path = Vec::new();
+            let mut at = cur;
+            while at != idx(from.0, from.1) {
+                path.push((x0 + at / h, y0 + at % h));
+                at = parent[at];
+            }
+            return Some(path);
+        }
+        if f > g[cur] + heuristic(cx, cy) { continue; } // застарілий запис у купі
+        for (dx, dy) in NEIGHBOURS {
+            let (nx, ny) = (cx as i32 + dx, cy as i32 + dy);
+            if nx < x0 as i32 || ny < y0 as i32 || nx > x1 as i32 || ny > y1 as i32 { continue; }
+            let (nx, ny) = (nx as usize, ny as usize);
+            let Some(cost) = step_cost(cells, biomes, nx, ny, dire) else { continue };
+            let diagonal = dx != 0 && dy != 0;
+            if diagonal && (step_cost(cells, biomes, nx, cy, dire).is_none() || step_cost(cells, biomes, cx, ny, dire).is_none()) { continue; }
+            let ng = g[cur] + if diagonal { cost * std::f32::consts::SQRT_2 } else { cost };
+            let n = idx(nx, ny);
+            if ng < g[n] {
+                g[n] = ng;
+                parent[n] = cur;
+                open.push(Node { f: ng + heuristic(nx, ny), idx: n });
+            }
+        }
+    }
+    None
+}
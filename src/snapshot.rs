@@ -0,0 +1,123 @@
+use rand_chacha::ChaCha8Rng;
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::path::Path;
+
+use crate::config::SimConfig;
+use crate::lineage::Lineage;
+use crate::spatial::SpatialGrid;
+use crate::world::{Agent, Cell, TickEvents, World};
+
+/// Поточна версія формату знімка. Збільшувати при будь-якій зміні полів.
+pub const SNAPSHOT_VERSION: u32 = 12;
+const SNAPSHOT_FORMAT: &str = "quadrisrah-snapshot";
+
+/// Тільки заголовок: читається першим, щоб відкинути чужі чи старі файли до повного розбору.
+#[derive(Deserialize)]
+struct Header {
+    format: String,
+    version: u32,
+}
+
+#[derive(Serialize, Deserialize)]
+struct Snapshot {
+    format: String,
+    version: u32,
+    seed: u64,
+    tick: u64,
+    config: SimConfig,
+    rng: ChaCha8Rng,
+    cells: Vec<Vec<Cell>>,
+    agents: Vec<Agent>,
+    lineage: Lineage,
+}
+
+#[derive(Debug)]
+pub enum SnapshotError {
+    Io(std::io::Error),
+    Parse(String),
+    NotASnapshot,
+    UnsupportedVersion { found: u32, expected: u32 },
+    Invalid(String),
+}
+
+impl fmt::Display for SnapshotError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            SnapshotError::Io(e) => write!(f, "snapshot i/o error: {}", e),
+            SnapshotError::Parse(e) => write!(f, "cannot parse snapshot: {}", e),
+            SnapshotError::NotASnapshot => write!(f, "file is not a {} file", SNAPSHOT_FORMAT),
+            SnapshotError::UnsupportedVersion { found, expected } => {
+                write!(f, "snapshot version {} is not supported (this build reads version {})", found, expected)
+            }
+            SnapshotError::Invalid(e) => write!(f, "invalid snapshot: {}", e),
+        }
+    }
+}
+
+impl std::error::Error for SnapshotError {}
+
+impl World {
+    /// Серіалізує весь стан світу, включно зі станом генератора.
+    pub fn to_snapshot_string(&self) -> String {
+        let snap = Snapshot {
+            format: SNAPSHOT_FORMAT.to_owned(),
+            version: SNAPSHOT_VERSION,
+            seed: self.seed,
+            tick: self.tick,
+            config: self.config.clone(),
+            rng: self.rng.clone(),
+            cells: self.cells.clone(),
+            agents: self.agents.clone(),
+            lineage: self.lineage.clone(),
+        };
+        serde_json::to_string(&snap).expect("world state is always serializable")
+    }
+
+    pub fn from_snapshot_str(text: &str) -> Result<World, SnapshotError> {
+        let header: Header = serde_json::from_str(text).map_err(|_| SnapshotError::NotASnapshot)?;
+        if header.format != SNAPSHOT_FORMAT { return Err(SnapshotError::NotASnapshot); }
+        if header.version != SNAPSHOT_VERSION {
+            return Err(SnapshotError::UnsupportedVersion { found: header.version, expected: SNAPSHOT_VERSION });
+        }
+        let snap: Snapshot = serde_json::from_str(text).map_err(|e| SnapshotError::Parse(e.to_string()))?;
+        snap.config.validate().map_err(|e| SnapshotError::Invalid(e.to_string()))?;
+        let size = snap.config.grid_size;
+        if snap.cells.len() != size || snap.cells.iter().any(|col| col.len() != size) {
+            return Err(SnapshotError::Invalid(format!("cell grid does not match grid_size {}", size)));
+        }
+        let biomes = snap.config.biome_set();
+        if let Some(c) = snap.cells.iter().flatten().find(|c| !biomes.contains(c.terrain)) {
+            return Err(SnapshotError::Invalid(format!("terrain {:?} is not part of biome set `{}`", c.terrain, biomes.name)));
+        }
+        if !snap.lineage.is_consistent() || snap.agents.iter().any(|a| snap.lineage.get(a.id).is_none()) {
+            return Err(SnapshotError::Invalid("agent ids do not match the lineage".into()));
+        }
+        // Засновники стоять будь-де в [0, size), а рух притискає до size - 1
+        let limit = size as f32;
+        if let Some(a) = snap.agents.iter().find(|a| !(0.0..limit).contains(&a.pos.x) || !(0.0..limit).contains(&a.pos.y)) {
+            return Err(SnapshotError::Invalid(format!("agent at ({}, {}) is outside the grid", a.pos.x, a.pos.y)));
+        }
+        Ok(World {
+            cells: snap.cells,
+            agents: snap.agents,
+            tick: snap.tick,
+            seed: snap.seed,
+            rng: snap.rng,
+            grid: SpatialGrid::new(size),
+            biomes: snap.config.biome_set(),
+            config: snap.config,
+            events: TickEvents::default(),
+            lineage: snap.lineage,
+        })
+    }
+
+    pub fn save(&self, path: impl AsRef<Path>) -> Result<(), SnapshotError> {
+        std::fs::write(path, self.to_snapshot_string()).map_err(SnapshotError::Io)
+    }
+
+    pub fn load(path: impl AsRef<Path>) -> Result<World, SnapshotError> {
+        let text = std::fs::read_to_string(path).map_err(SnapshotError::Io)?;
+        Self::from_snapshot_str(&text)
+    }
+}
@@ -0,0 +1,112 @@
+use glam::Vec2;
+
+use crate::world::{Agent, AgentKind};
+
+/// Розмір комірки індексу у клітинках світу.
+pub const BUCKET_SIZE: f32 = 8.0;
+
+/// Рівномірна сітка відер з індексами агентів, окремо для кожного `AgentKind`.
+///
+/// Запити повертають індекси у зростаючому порядку, тож код, що перебирає їх,
+/// поводиться так само, як старий повний перебір `for j in 0..agent_count`.
+pub struct SpatialGrid {
+    dim: usize,
+    buckets: [Vec<Vec<usize>>; 2],
+    slot: Vec<usize>,
+    /// Запити перебирають усіх агентів — еталон для перевірки сітки.
+    #[cfg(test)]
+    brute: bool,
+}
+
+fn kind_slot(kind: AgentKind) -> usize {
+    match kind { AgentKind::Valkarai => 0, AgentKind::Zombie => 1 }
+}
+
+impl SpatialGrid {
+    pub fn new(world_size: usize) -> Self {
+        let dim = (world_size as f32 / BUCKET_SIZE).ceil().max(1.0) as usize;
+        SpatialGrid {
+            dim, buckets: [vec![Vec::new(); dim * dim], vec![Vec::new(); dim * dim]], slot: Vec::new(),
+            #[cfg(test)]
+            brute: false,
+        }
+    }
+
+    /// Той самий інтерфейс, але кожен запит — повний перебір, як до появи сітки.
+    #[cfg(test)]
+    fn brute_force(world_size: usize) -> Self {
+        SpatialGrid { brute: true, ..Self::new(world_size) }
+    }
+
+    fn coord(&self, v: f32) -> usize {
+        ((v / BUCKET_SIZE) as usize).min(self.dim - 1)
+    }
+
+    fn bucket_of(&self, p: Vec2) -> usize {
+        self.coord(p.x) * self.dim + self.coord(p.y)
+    }
+
+    /// Заново розкладає всіх агентів по відрах.
+    pub fn rebuild(&mut self, agents: &[Agent]) {
+        for kind in &mut self.buckets { for b in kind.iter_mut() { b.clear(); } }
+        self.slot.clear();
+        for (i, a) in agents.iter().enumerate() {
+            let b = self.bucket_of(a.pos);
+            self.buckets[kind_slot(a.kind)][b].push(i);
+            self.slot.push(b);
+        }
+    }
+
+    /// Переносить агента `idx` у відро нової позиції, якщо він його змінив.
+    pub fn update(&mut self, idx: usize, kind: AgentKind, pos: Vec2) {
+        let new_b = self.bucket_of(pos);
+        let old_b = self.slot[idx];
+        if new_b == old_b { return; }
+        let bucket = &mut self.buckets[kind_slot(kind)][old_b];
+        if let Some(k) = bucket.iter().position(|&j| j == idx) { bucket.swap_remove(k); }
+        self.buckets[kind_slot(kind)][new_b].push(idx);
+        self.slot[idx] = new_b;
+    }
+
+    /// Агенти виду `kind`, чия відстань до `p` строго менша за `r`, у зростаючому порядку індексів.
+    pub fn query(&self, agents: &[Agent], kind: AgentKind, p: Vec2, r: f32, out: &mut Vec<usize>) {
+        out.clear();
+        if r.is_nan() || r <= 0.0 { return; }
+        #[cfg(test)]
+        if self.brute {
+            out.extend((0..agents.len()).filter(|&j| agents[j].kind == kind && p.distance(agents[j].pos) < r));
+            return;
+        }
+        let (x0, x1) = (self.coord((p.x - r).max(0.0)), self.coord((p.x + r).max(0.0)));
+        let (y0, y1) = (self.coord((p.y - r).max(0.0)), self.coord((p.y + r).max(0.0)));
+        let buckets = &self.buckets[kind_slot(kind)];
+        for bx in x0..=x1 {
+            for by in y0..=y1 {
+                for &j in &buckets[bx * self.dim + by] {
+                    if p.distance(agents[j].pos) < r { out.push(j); }
+                }
+            }
+        }
+        out.sort_unstable();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::SpatialGrid;
+    use crate::World;
+
+    #[test]
+    fn simulation_matches_brute_force_lookup() {
+        let mut grid = World::with_seed(21);
+        let mut brute = World::with_seed(21);
+        brute.grid = SpatialGrid::brute_force(brute.size());
+        for _ in 0..600 {
+            grid.update();
+            brute.update();
+        }
+        assert!(grid.agents().len() > 1);
+        assert_eq!(grid.agents(), brute.agents());
+        assert_eq!(grid.lineage(), brute.lineage());
+    }
+}
@@ -0,0 +1,151 @@
+use std::io::{self, Write};
+use std::path::Path;
+
+use crate::world::{AgentKind, Terrain, World};
+
+/// Мінімум, середнє і максимум одного гена серед живих Валкараїв.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct GeneStats {
+    pub mean: f32,
+    pub min: f32,
+    pub max: f32,
+}
+
+impl GeneStats {
+    fn of(values: impl Iterator<Item = f32>) -> Self {
+        let (mut n, mut sum, mut min, mut max) = (0usize, 0.0f32, f32::MAX, f32::MIN);
+        for v in values { n += 1; sum += v; min = min.min(v); max = max.max(v); }
+        if n == 0 { return GeneStats::default(); }
+        GeneStats { mean: sum / n as f32, min, max }
+    }
+}
+
+/// Один рядок статистики, знятий після `World::update`.
+#[derive(Clone, Debug, PartialEq)]
+pub struct TickStats {
+    pub tick: u64,
+    pub valkarai: usize,
+    pub zombies: usize,
+    pub births: u32,
+    pub infections: u32,
+    pub starvation_deaths: u32,
+    pub zombies_starved: u32,
+    pub old_age_deaths: u32,
+    pub decay_deaths: u32,
+    pub valkarai_killed: u32,
+    pub zombies_killed: u32,
+    pub speed: GeneStats,
+    pub vision: GeneStats,
+    pub resistance: GeneStats,
+    /// Сумарна `food_level` по кожному біому світу, у порядку набору біомів.
+    pub food: Vec<(Terrain, f32)>,
+    pub mean_energy_valkarai: f32,
+    pub mean_energy_zombie: f32,
+}
+
+impl TickStats {
+    pub fn collect(world: &World) -> Self {
+        let ev = world.last_events();
+        let mut food: Vec<(Terrain, f32)> = world.biomes().terrains().map(|t| (t, 0.0)).collect();
+        for cell in world.cells().iter().flatten() {
+            if let Some(entry) = food.iter_mut().find(|(t, _)| *t == cell.terrain) { entry.1 += cell.food_level; }
+        }
+        let mean_energy = |kind| {
+            let (n, sum) = world.agents_of(kind).fold((0usize, 0.0f32), |(n, s), a| (n + 1, s + a.energy));
+            if n == 0 { 0.0 } else { sum / n as f32 }
+        };
+        TickStats {
+            tick: world.tick(),
+            valkarai: world.count(AgentKind::Valkarai),
+            zombies: world.count(AgentKind::Zombie),
+            births: ev.births,
+            infections: ev.infections,
+            starvation_deaths: ev.starvation_deaths,
+            zombies_starved: ev.zombies_starved,
+            old_age_deaths: ev.old_age_deaths,
+            decay_deaths: ev.decay_deaths,
+            valkarai_killed: ev.valkarai_killed,
+            zombies_killed: ev.zombies_killed,
+            speed: GeneStats::of(world.agents_of(AgentKind::Valkarai).map(|a| a.speed_gen)),
+            vision: GeneStats::of(world.agents_of(AgentKind::Valkarai).map(|a| a.vision_gen)),
+            resistance: GeneStats::of(world.agents_of(AgentKind::Valkarai).map(|a| a.resistance_gen)),
+            food,
+            mean_energy_valkarai: mean_energy(AgentKind::Valkarai),
+            mean_energy_zombie: mean_energy(AgentKind::Zombie),
+        }
+    }
+}
+
+/// Накопичує `TickStats` по тіках і вивантажує їх у CSV.
+///
+/// Колонки їжі залежать від набору біомів; якщо він змінився, записане раніше відкидається.
+/// Новий світ того самого набору (завантажений знімок) так не розпізнати — тоді викликайте `clear`.
+#[derive(Default)]
+pub struct StatsRecorder {
+    rows: Vec<TickStats>,
+    terrains: Vec<Terrain>,
+    /// Скільки останніх тіків тримати; `None` — всю історію.
+    limit: Option<usize>,
+}
+
+impl StatsRecorder {
+    /// Пам'ятає всю історію — для CSV.
+    pub fn new() -> Self { Self::default() }
+
+    /// Пам'ятає лише останні `limit` тіків: пам'ять не росте, скільки б симуляція не йшла.
+    pub fn bounded(limit: usize) -> Self { StatsRecorder { limit: Some(limit.max(1)), ..Self::default() } }
+
+    /// Викликати після кожного `World::update`.
+    pub fn record(&mut self, world: &World) {
+        if !world.biomes().terrains().eq(self.terrains.iter().copied()) {
+            self.rows.clear();
+            self.terrains = world.biomes().terrains().collect();
+        }
+        self.rows.push(TickStats::collect(world));
+        // Старе зсуваємо раз на `limit` записів, а не щотіку
+        if let Some(limit) = self.limit {
+            if self.rows.len() >= 2 * limit { self.rows.drain(..self.rows.len() - limit); }
+        }
+    }
+
+    /// Забуває все записане: наступний `record` почне новий запуск.
+    pub fn clear(&mut self) {
+        self.rows.clear();
+        self.terrains.clear();
+    }
+
+    pub fn rows(&self) -> &[TickStats] {
+        let keep = self.limit.unwrap_or(self.rows.len()).min(self.rows.len());
+        &self.rows[self.rows.len() - keep..]
+    }
+
+    pub fn header(&self) -> String {
+        let mut cols: Vec<String> = [
+            "tick", "valkarai", "zombies", "births", "infections", "starvation_deaths", "zombies_starved", "old_age_deaths", "decay_deaths", "valkarai_killed", "zombies_killed",
+            "speed_mean", "speed_min", "speed_max", "vision_mean", "vision_min", "vision_max",
+            "resistance_mean", "resistance_min", "resistance_max",
+        ].iter().map(|s| s.to_string()).collect();
+        cols.extend(self.terrains.iter().map(|t| format!("food_{}", t.name())));
+        cols.push("mean_energy_valkarai".into());
+        cols.push("mean_energy_zombie".into());
+        cols.join(",")
+    }
+
+    pub fn write_csv(&self, mut out: impl Write) -> io::Result<()> {
+        writeln!(out, "{}", self.header())?;
+        for r in self.rows() {
+            write!(out, "{},{},{},{},{},{},{},{},{},{},{}", r.tick, r.valkarai, r.zombies, r.births, r.infections,
+                r.starvation_deaths, r.zombies_starved, r.old_age_deaths, r.decay_deaths, r.valkarai_killed, r.zombies_killed)?;
+            for g in [r.speed, r.vision, r.resistance] { write!(out, ",{},{},{}", g.mean, g.min, g.max)?; }
+            for (_, f) in &r.food { write!(out, ",{}", f)?; }
+            writeln!(out, ",{},{}", r.mean_energy_valkarai, r.mean_energy_zombie)?;
+        }
+        Ok(())
+    }
+
+    pub fn save_csv(&self, path: impl AsRef<Path>) -> io::Result<()> {
+        let mut out = io::BufWriter::new(std::fs::File::create(path)?);
+        self.write_csv(&mut out)?;
+        out.flush()
+    }
+}
@@ -0,0 +1,68 @@
+use crate::biome::{BiomeDef, BiomeSet};
+use crate::config::SimConfig;
+use crate::world::Cell;
+
+/// Рослини ростуть раз на стільки тіків — з кроком, помноженим на цей інтервал.
+pub const GROWTH_INTERVAL: u64 = 10;
+/// Частка ємності, з якою клітинка починає: молоді пагони.
+pub const INITIAL_COVER: f32 = 0.25;
+/// Частка ємності, яку Валкарай з'їдає за тік: повна клітинка витримує кілька укусів.
+pub const BITE: f32 = 0.2;
+
+/// Скільки їжі клітинка має на початку світу.
+pub(crate) fn initial_food(biome: &BiomeDef) -> f32 {
+    biome.capacity * INITIAL_COVER
+}
+
+/// Наповненість клітинки, 0..1; там, де нічого не росте, — нуль.
+pub fn fill(cell: &Cell, biome: &BiomeDef) -> f32 {
+    if biome.capacity > 0.0 { (cell.food_level / biome.capacity).min(1.0) } else { 0.0 }
+}
+
+/// Один крок росту за `GROWTH_INTERVAL` тіків.
+///
+/// Логістичний приріст `growth * F * (1 - F / capacity)` плюс насіння від чотирьох сусідів:
+/// `seed_rate * capacity * (1 - F / capacity)`, помножене на їхню середню наповненість —
+/// тільки так оживає з'їдена до нуля клітинка. Обидва доданки множаться на `vigor`,
+/// яке щотіку відновлюється на `vigor_recovery`. Понад ємність (після редактора) їжа спадає,
+/// а на клітинках з нульовою ємністю одразу зникає.
+pub(crate) fn grow(cells: &mut [Vec<Cell>], biomes: &BiomeSet, cfg: &SimConfig) {
+    let size = cells.len();
+    let dt = GROWTH_INTERVAL as f32;
+    // Насіння рахуємо від стану до кроку, щоб порядок обходу не мав значення
+    let fills: Vec<Vec<f32>> = cells.iter().map(|col| col.iter().map(|c| fill(c, biomes.get(c.terrain))).collect()).collect();
+    for x in 0..size {
+        for y in 0..size {
+            let cell = &mut cells[x][y];
+            let b = biomes.get(cell.terrain);
+            cell.vigor = (cell.vigor + cfg.vigor_recovery * dt).min(1.0);
+            // Там, де нічого не росте, принесена їжа зникає за один крок
+            if b.capacity <= 0.0 {
+                cell.food_level = 0.0;
+                continue;
+            }
+            let mut seeds = 0.0;
+            if x > 0 { seeds += fills[x - 1][y]; }
+            if x + 1 < size { seeds += fills[x + 1][y]; }
+            if y > 0 { seeds += fills[x][y - 1]; }
+            if y + 1 < size { seeds += fills[x][y + 1]; }
+            let room = 1.0 - cell.food_level / b.capacity;
+            let rate = b.growth * cell.food_level * room + cfg.seed_rate * b.capacity * (seeds / 4.0) * room.max(0.0);
+            // Слабкість гальмує ріст, але не в'янення надлишку
+            let rate = if rate > 0.0 { rate * cell.vigor } else { rate };
+            cell.food_level = (cell.food_level + rate * dt).max(0.0);
+        }
+    }
+}
+
+/// Валкарай з'їдає з клітинки `BITE` її ємності; з'їдена до нуля клітинка втрачає `grazing_damage` сили.
+/// Повертає, скільки з'їдено.
+pub(crate) fn graze(cell: &mut Cell, biome: &BiomeDef, cfg: &SimConfig) -> f32 {
+    let eaten = (biome.capacity * BITE).min(cell.food_level);
+    cell.food_level -= eaten;
+    if cell.food_level <= 0.0 {
+        cell.food_level = 0.0;
+        cell.vigor = (cell.vigor - cfg.grazing_damage).max(0.0);
+    }
+    eaten
+}
@@ -0,0 +1,520 @@
+use ::rand::{Rng, SeedableRng};
+use glam::{vec2, Vec2};
+use rand_chacha::ChaCha8Rng;
+use serde::{Deserialize, Serialize};
+
+use crate::biome::{BiomeSet, Passability};
+use crate::climate::Climate;
+use crate::combat;
+use crate::config::SimConfig;
+use crate::lineage::{DeathCause, Lineage};
+use crate::path;
+use crate::spatial::SpatialGrid;
+use crate::vegetation;
+
+// --- СТРУКТУРИ ---
+#[derive(Clone, Copy, PartialEq, Debug, Serialize, Deserialize)]
+pub enum Terrain { Mountain, Tundra, Forest, Plains, Desert, Ocean }
+
+impl Terrain {
+    pub const ALL: [Terrain; 6] = [Terrain::Mountain, Terrain::Tundra, Terrain::Forest, Terrain::Plains, Terrain::Desert, Terrain::Ocean];
+
+    pub fn name(self) -> &'static str {
+        match self {
+            Terrain::Mountain => "mountain", Terrain::Tundra => "tundra", Terrain::Forest => "forest",
+            Terrain::Plains => "plains", Terrain::Desert => "desert", Terrain::Ocean => "ocean",
+        }
+    }
+}
+#[derive(Clone, Copy, PartialEq, Debug, Serialize, Deserialize)]
+pub enum AgentKind { Valkarai, Zombie }
+
+/// Що агент робив на останньому тіку.
+#[derive(Clone, Copy, PartialEq, Debug, Default, Serialize, Deserialize)]
+pub enum AgentState {
+    #[default]
+    Wandering,
+    SeekingFood,
+    SeekingMate,
+    Fleeing,
+    /// Зомбі женеться за Валкараєм.
+    Chasing,
+}
+
+impl AgentState {
+    pub fn name(self) -> &'static str {
+        match self {
+            AgentState::Wandering => "wandering", AgentState::SeekingFood => "seeking food",
+            AgentState::SeekingMate => "seeking a mate", AgentState::Fleeing => "fleeing", AgentState::Chasing => "chasing",
+        }
+    }
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct Cell {
+    pub terrain: Terrain,
+    pub food_level: f32,
+    /// Сила рослин, 0..1: множник росту, який падає від випасу до нуля.
+    pub vigor: f32,
+    /// Сирі поля клімату, з яких обрано `terrain`: висота -1..1, температура й вологість 0..1.
+    pub elevation: f32,
+    pub temperature: f32,
+    pub moisture: f32,
+    /// Скільки агентів померло тут від голоду за всю історію світу.
+    pub starvation_deaths: u32,
+    /// Скільки Валкараїв тут заразилось за всю історію світу.
+    pub infections: u32,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct Agent {
+    /// Стабільний ідентифікатор, ніколи не використовується повторно.
+    pub id: u64,
+    /// Батьки; `None` у засновників, створених разом зі світом.
+    pub parents: Option<(u64, u64)>,
+    pub generation: u32,
+    pub birth_tick: u64,
+    /// Прожиті тіки; у зомбі рахунок іде далі з віку до зараження.
+    pub age: u64,
+    pub pos: Vec2,
+    pub energy: f32,
+    pub reproduce_cooldown: f32,
+    pub speed_gen: f32,
+    pub vision_gen: f32,
+    /// Спадкова тривалість життя в тіках; після неї Валкарай помирає від старості.
+    pub lifespan_gen: f32,
+    /// Спадковий опір зараженню, 0..1; коштує енергії щотіку.
+    pub resistance_gen: f32,
+    /// Скільки тіків поспіль агент у контакті з зомбі.
+    pub exposure: u32,
+    pub kind: AgentKind,
+    /// Поточне здоров'я; відновлюється до `max_health`.
+    pub health: f32,
+    /// Спадковий запас здоров'я.
+    pub max_health: f32,
+    /// Спадкова сила удару в ближньому бою.
+    pub damage: f32,
+    pub state: AgentState,
+    /// Куди агент йшов на останньому тіку; для `Fleeing` — позиція зомбі, від якого тікає.
+    pub target: Option<Vec2>,
+    /// Решта прокладеного шляху, від цілі до наступного кроку (див. `path::find_path`).
+    pub path: Vec<(usize, usize)>,
+    /// Клітинка цілі, до якої прокладено `path`; коли ціль переходить в іншу — шлях прокладається заново.
+    pub path_goal: Option<(usize, usize)>,
+}
+
+/// Події останнього тіку — для статистики.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct TickEvents {
+    pub births: u32,
+    pub infections: u32,
+    /// Валкараї, що померли з голоду.
+    pub starvation_deaths: u32,
+    /// Зомбі, що померли з голоду.
+    pub zombies_starved: u32,
+    pub old_age_deaths: u32,
+    /// Зомбі, що розклались (здоров'я вичерпалось без бою).
+    pub decay_deaths: u32,
+    /// Валкараї, вбиті в бою (здоров'я впало до нуля раніше, ніж їх заразили).
+    pub valkarai_killed: u32,
+    pub zombies_killed: u32,
+}
+
+/// Увесь стан симуляції: сітка клітинок `cells[x][y]` та список агентів.
+///
+/// Уся випадковість (рельєф, розстановка, їжа, блукання, мутації) йде з одного
+/// генератора `rng`, тож однаковий `seed` дає побітово однаковий прогін.
+pub struct World {
+    pub(crate) cells: Vec<Vec<Cell>>,
+    pub(crate) agents: Vec<Agent>,
+    pub(crate) tick: u64,
+    pub(crate) seed: u64,
+    pub(crate) rng: ChaCha8Rng,
+    pub(crate) config: SimConfig,
+    pub(crate) biomes: BiomeSet,
+    pub(crate) grid: SpatialGrid,
+    pub(crate) events: TickEvents,
+    pub(crate) lineage: Lineage,
+}
+
+/// Частки `lifespan_gen`: до першої агент ще не плодючий, після другої — вже ні.
+const MATURITY: f32 = 0.15;
+const MENOPAUSE: f32 = 0.8;
+/// З цієї частки життя обмін речовин росте до `OLD_METABOLISM` наприкінці.
+const AGING: f32 = 0.6;
+const YOUNG_METABOLISM: f32 = 0.8;
+const OLD_METABOLISM: f32 = 1.5;
+/// Тривалість життя агентів, поставлених редактором, і початкового зомбі (зомбі не старіють, а розкладаються).
+pub(crate) const DEFAULT_LIFESPAN: f32 = 8000.0;
+/// До клітинки варто йти, лише якщо там є хоч на один укус: частка `BITE` її ємності.
+fn worth_foraging(food_level: f32, capacity: f32) -> bool {
+    food_level > 0.0 && food_level >= vegetation::BITE * capacity
+}
+
+impl Agent {
+    /// Валкарай без батьків: засновник світу або поставлений редактором. З'являється вже дорослим.
+    pub(crate) fn founder(id: u64, pos: Vec2, speed_gen: f32, vision_gen: f32, lifespan_gen: f32, resistance_gen: f32, birth_tick: u64) -> Self {
+        Agent {
+            id, parents: None, generation: 0, birth_tick, age: (lifespan_gen * MATURITY) as u64,
+            pos, energy: 100.0, reproduce_cooldown: 0.0,
+            speed_gen, vision_gen, lifespan_gen, resistance_gen, exposure: 0,
+            kind: AgentKind::Valkarai, health: 100.0, max_health: 100.0, damage: 10.0,
+            state: AgentState::Wandering, target: None, path: Vec::new(), path_goal: None,
+        }
+    }
+
+    /// Початковий зомбі, з якого починається спалах.
+    pub(crate) fn patient_zero(id: u64, pos: Vec2, energy: f32, birth_tick: u64) -> Self {
+        Agent {
+            id, parents: None, generation: 0, birth_tick, age: 0,
+            pos, energy, reproduce_cooldown: 0.0,
+            speed_gen: 0.15, vision_gen: 15.0, lifespan_gen: DEFAULT_LIFESPAN, resistance_gen: 0.0, exposure: 0, kind: AgentKind::Zombie, health: 300.0, max_health: 300.0, damage: 20.0,
+            state: AgentState::Wandering, target: None, path: Vec::new(), path_goal: None,
+        }
+    }
+}
+
+impl Agent {
+    /// Чи агент у віці, коли може мати дітей.
+    pub fn is_fertile(&self) -> bool {
+        let t = self.age as f32 / self.lifespan_gen;
+        (MATURITY..MENOPAUSE).contains(&t)
+    }
+
+    /// Множник витрат енергії за тік: молоді витрачають менше, старі — дедалі більше.
+    pub fn metabolism(&self) -> f32 {
+        let t = self.age as f32 / self.lifespan_gen;
+        if t < MATURITY { YOUNG_METABOLISM }
+        else if t < AGING { 1.0 }
+        else { 1.0 + (OLD_METABOLISM - 1.0) * ((t - AGING) / (1.0 - AGING)).min(1.0) }
+    }
+}
+
+/// Клітинка, в якій стоїть точка; позиції агентів завжди в межах сітки.
+pub(crate) fn cell_of(p: Vec2) -> (usize, usize) { (p.x as usize, p.y as usize) }
+
+impl World {
+    /// Світ з випадковим зерном і параметрами за замовчуванням.
+    pub fn new() -> Self {
+        Self::with_seed(::rand::random())
+    }
+
+    pub fn with_seed(seed: u64) -> Self {
+        Self::with_config(SimConfig::default(), seed)
+    }
+
+    /// `config` має пройти `SimConfig::validate`.
+    pub fn with_config(config: SimConfig, seed: u64) -> Self {
+        let grid_size = config.grid_size;
+        let biomes = config.biome_set();
+        let mut rng = ChaCha8Rng::seed_from_u64(seed);
+        let climate = Climate::new(&mut rng);
+        let mut cells = Vec::new();
+
+        for x in 0..grid_size {
+            let mut row = Vec::new();
+            for y in 0..grid_size {
+                let (elevation, temperature, moisture) = climate.sample(x, y, grid_size);
+                let terrain = biomes.classify(elevation, temperature, moisture);
+                let food_level = vegetation::initial_food(biomes.get(terrain));
+                row.push(Cell { terrain, food_level, vigor: 1.0, elevation, temperature, moisture, starvation_deaths: 0, infections: 0 });
+            }
+            cells.push(row);
+        }
+        Self::populate(config, seed, rng, cells)
+    }
+
+    /// Розставляє засновників і початкового зомбі на готовій карті `cells`.
+    pub(crate) fn populate(config: SimConfig, seed: u64, mut rng: ChaCha8Rng, cells: Vec<Vec<Cell>>) -> Self {
+        let grid_size = config.grid_size;
+        let biomes = config.biome_set();
+        let is_wall = |p: Vec2| biomes.get(cells[p.x as usize][p.y as usize].terrain).passable == Passability::Wall;
+        // Шукаємо прохідну клітинку; на карті з самих стін здаємося після багатьох спроб
+        let random_pos = |rng: &mut ChaCha8Rng| {
+            let mut p = Vec2::ZERO;
+            for _ in 0..10_000 {
+                p = vec2(rng.gen_range(0.0..grid_size as f32), rng.gen_range(0.0..grid_size as f32));
+                if !is_wall(p) { break; }
+            }
+            p
+        };
+
+        let mut agents = Vec::new();
+        for id in 0..config.initial_valkarai as u64 {
+            let pos = random_pos(&mut rng);
+            let speed_gen = rng.gen_range(0.12..0.22);
+            let vision_gen = rng.gen_range(10.0..20.0);
+            let lifespan_gen = rng.gen_range(6000.0..10000.0);
+            let resistance_gen = rng.gen_range(0.0..0.2);
+            agents.push(Agent::founder(id, pos, speed_gen, vision_gen, lifespan_gen, resistance_gen, 0));
+        }
+        let mut zombie_pos = Vec2::splat(50.0f32.min(grid_size as f32 - 1.0));
+        if is_wall(zombie_pos) { zombie_pos = random_pos(&mut rng); }
+        agents.push(Agent::patient_zero(agents.len() as u64, zombie_pos, config.zombie_energy, 0));
+        let mut lineage = Lineage::default();
+        for a in &agents { lineage.record_birth(a); }
+        World { cells, agents, tick: 0, seed, rng, config, biomes, grid: SpatialGrid::new(grid_size), events: TickEvents::default(), lineage }
+    }
+
+    /// Один тік симуляції.
+    pub fn update(&mut self) {
+        self.tick += 1;
+        let now = self.tick;
+        let rng = &mut self.rng;
+        let cfg = &self.config;
+        let biomes = &self.biomes;
+        let grid_size = cfg.grid_size;
+        if now.is_multiple_of(vegetation::GROWTH_INTERVAL) { vegetation::grow(&mut self.cells, biomes, cfg); }
+
+        let mut contacts = Vec::new();
+        let agent_count = self.agents.len();
+        let mut near = Vec::new();
+        self.grid.rebuild(&self.agents);
+
+        for i in 0..agent_count {
+            if self.agents[i].reproduce_cooldown > 0.0 { self.agents[i].reproduce_cooldown -= 1.0; }
+            let pos = self.agents[i].pos;
+            let kind = self.agents[i].kind;
+            let vision = self.agents[i].vision_gen;
+            
+            let mut target: Option<Vec2> = None;
+            let mut flee_dir: Option<Vec2> = None;
+            let mut state = AgentState::Wandering;
+
+            if kind == AgentKind::Zombie {
+                let mut min_d = vision;
+                self.grid.query(&self.agents, AgentKind::Valkarai, pos, vision.max(cfg.mating_distance), &mut near);
+                for &j in &near {
+                    let d = pos.distance(self.agents[j].pos);
+                    if d < min_d { min_d = d; target = Some(self.agents[j].pos); }
+                    if d < cfg.mating_distance { contacts.push((i, j)); }
+                }
+                if target.is_some() { state = AgentState::Chasing; }
+            } else {
+                // Тікаємо від останнього (за індексом) зомбі в полі зору
+                self.grid.query(&self.agents, AgentKind::Zombie, pos, vision * 0.8, &mut near);
+                if let Some(&j) = near.last() {
+                    flee_dir = Some(pos - self.agents[j].pos);
+                    state = AgentState::Fleeing;
+                }
+                if flee_dir.is_none() {
+                    // Шукаємо пару лише серед плодючих: з іншими спаровування все одно не вийде
+                    if self.agents[i].energy > cfg.reproduction_threshold && self.agents[i].reproduce_cooldown == 0.0 && self.agents[i].is_fertile() {
+                        let mut min_m = vision * 1.5;
+                        self.grid.query(&self.agents, AgentKind::Valkarai, pos, min_m, &mut near);
+                        for &j in &near {
+                            if i == j { continue; }
+                            let d = pos.distance(self.agents[j].pos);
+                            let partner = &self.agents[j];
+                            if d < min_m && partner.energy > cfg.reproduction_threshold && partner.reproduce_cooldown == 0.0 && partner.is_fertile() {
+                                min_m = d; target = Some(self.agents[j].pos);
+                            }
+                        }
+                        if target.is_some() { state = AgentState::SeekingMate; }
+                    }
+                    if target.is_none() {
+                        // Чим менше, тим краще; біоми з food_score > 1 здаються далі, ніж є
+                        let mut best_score = vision;
+                        let v_int = vision as i32;
+                        for ox in -v_int..=v_int {
+                            for oy in -v_int..=v_int {
+                                let cx = (pos.x as i32 + ox).clamp(0, grid_size as i32 - 1) as usize;
+                                let cy = (pos.y as i32 + oy).clamp(0, grid_size as i32 - 1) as usize;
+                                let biome = biomes.get(self.cells[cx][cy].terrain);
+                                if worth_foraging(self.cells[cx][cy].food_level, biome.capacity) {
+                                    if biome.passable == Passability::Wall { continue; }
+                                    let d = pos.distance(vec2(cx as f32 + 0.5, cy as f32 + 0.5));
+                                    let score = d * biome.food_score;
+                                    if d < vision && score < best_score {
+                                        best_score = score; target = Some(vec2(cx as f32 + 0.5, cy as f32 + 0.5)); state = AgentState::SeekingFood;
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+
+            let speed_mult = biomes.get(self.cells[pos.x as usize][pos.y as usize].terrain).speed;
+            let cur_speed = self.agents[i].speed_gen * speed_mult;
+
+            // Перевірка перешкод: океан — стіна для всіх, пустеля — для ситих Валкараїв
+            let dire = kind == AgentKind::Zombie || self.agents[i].energy < cfg.dire_energy || flee_dir.is_some();
+            let can_stand_at = |p: Vec2| {
+                let x = p.x.clamp(0.0, (grid_size - 1) as f32) as usize;
+                let y = p.y.clamp(0.0, (grid_size - 1) as f32) as usize;
+                match biomes.get(self.cells[x][y].terrain).passable {
+                    Passability::Open => true,
+                    Passability::Wall => false,
+                    Passability::DireOnly => dire,
+                }
+            };
+
+            // До цілі йдемо збереженим шляхом; прокладаємо заново, коли ціль перейшла в іншу
+            // клітинку або наступний крок став непрохідним (агент наївся і пустеля закрилась)
+            let mut steer = target;
+            let agent = &mut self.agents[i];
+            match target.filter(|_| flee_dir.is_none()) {
+                Some(t) => {
+                    let (here, goal) = (cell_of(pos), cell_of(t));
+                    let blocked = agent.path.last().is_some_and(|&(x, y)| !can_stand_at(vec2(x as f32 + 0.5, y as f32 + 0.5)));
+                    if agent.path_goal != Some(goal) || blocked {
+                        agent.path = path::find_path(&self.cells, biomes, here, goal, dire).unwrap_or_default();
+                        agent.path_goal = Some(goal);
+                    }
+                    while agent.path.last() == Some(&here) { agent.path.pop(); }
+                    if let Some(&(x, y)) = agent.path.last() { steer = Some(vec2(x as f32 + 0.5, y as f32 + 0.5)); }
+                }
+                None => { agent.path.clear(); agent.path_goal = None; }
+            }
+
+            let desired_move = if let Some(dir) = flee_dir { dir.normalize() * (cur_speed * 1.3) }
+                else if let Some(t) = steer { let d = t - pos; if d.length() > 0.1 { d.normalize() * cur_speed } else { Vec2::ZERO } }
+                else { vec2(rng.gen_range(-0.1..0.1), rng.gen_range(-0.1..0.1)) * speed_mult };
+            let mut next_pos = pos + desired_move;
+            // Якщо прямий шлях заблоковано, пробуємо ковзати окремо по X та Y, інакше стоїмо
+            if !can_stand_at(next_pos) {
+                let next_x = vec2(pos.x + desired_move.x, pos.y);
+                let next_y = vec2(pos.x, pos.y + desired_move.y);
+                next_pos = if can_stand_at(next_x) { next_x } else if can_stand_at(next_y) { next_y } else { pos };
+            }
+            self.agents[i].pos = next_pos;
+            self.agents[i].state = state;
+            self.agents[i].target = if let Some(dir) = flee_dir { Some(pos - dir) } else { target };
+
+            self.agents[i].pos.x = self.agents[i].pos.x.clamp(0.0, (grid_size - 1) as f32);
+            self.agents[i].pos.y = self.agents[i].pos.y.clamp(0.0, (grid_size - 1) as f32);
+            self.grid.update(i, kind, self.agents[i].pos);
+
+            // Валкараї загоюються; зомбі натомість розкладаються в кінці тіку
+            let a = &mut self.agents[i];
+            match kind {
+                AgentKind::Valkarai => a.health = (a.health + cfg.health_regen).min(a.max_health),
+                AgentKind::Zombie => a.energy -= cfg.zombie_metabolism,
+            }
+
+            if kind == AgentKind::Valkarai {
+                let a = &mut self.agents[i];
+                a.energy -= (0.1 + (a.vision_gen * 0.006) + (a.speed_gen * 0.45) + (a.resistance_gen * 0.3)) * a.metabolism();
+                let (nx, ny) = (self.agents[i].pos.x as usize, self.agents[i].pos.y as usize);
+                if self.cells[nx][ny].food_level > 0.0 && self.agents[i].energy < 100.0 {
+                    let biome = biomes.get(self.cells[nx][ny].terrain);
+                    self.agents[i].energy += vegetation::graze(&mut self.cells[nx][ny], biome, cfg) * 1.5;
+                }
+            }
+        }
+        let mut events = TickEvents::default();
+        for idx in combat::resolve(&mut self.agents, &contacts, cfg, rng, &mut events) {
+            self.lineage.record_infection(self.agents[idx].id, now);
+            let (x, y) = cell_of(self.agents[idx].pos);
+            self.cells[x][y].infections += 1;
+        }
+
+        let mut newborns = Vec::new();
+        let mut mated = vec![false; self.agents.len()];
+        self.grid.rebuild(&self.agents);
+        for i in 0..self.agents.len() {
+            if self.agents[i].kind == AgentKind::Zombie || mated[i] || self.agents[i].health <= 0.0 || self.agents[i].energy < cfg.reproduction_threshold || !self.agents[i].is_fertile() { continue; }
+            self.grid.query(&self.agents, AgentKind::Valkarai, self.agents[i].pos, cfg.mating_distance, &mut near);
+            for &j in near.iter().filter(|&&j| j > i) {
+                if !mated[j] && self.agents[j].health > 0.0 && self.agents[j].energy > cfg.reproduction_threshold && self.agents[j].is_fertile() {
+                    mated[i] = true; mated[j] = true;
+                    self.agents[i].energy -= 50.0; self.agents[j].energy -= 50.0;
+                    let mut cs = (self.agents[i].speed_gen + self.agents[j].speed_gen) / 2.0;
+                    let mut cv = (self.agents[i].vision_gen + self.agents[j].vision_gen) / 2.0;
+                    let mut ch = (self.agents[i].max_health + self.agents[j].max_health) / 2.0;
+                    let mut cd = (self.agents[i].damage + self.agents[j].damage) / 2.0;
+                    let mut cl = (self.agents[i].lifespan_gen + self.agents[j].lifespan_gen) / 2.0;
+                    let mut cr = (self.agents[i].resistance_gen + self.agents[j].resistance_gen) / 2.0;
+                    if rng.gen_bool(0.1) {
+                        cs *= rng.gen_range(0.9..1.1); cv *= rng.gen_range(0.9..1.1); ch *= rng.gen_range(0.9..1.1);
+                        cd *= rng.gen_range(0.9..1.1); cl *= rng.gen_range(0.9..1.1); cr *= rng.gen_range(0.9..1.1);
+                    }
+                    let ch = ch.clamp(50.0, 200.0);
+                    let (pa, pb) = (&self.agents[i], &self.agents[j]);
+                    newborns.push(Agent {
+                        id: self.lineage.next_id() + newborns.len() as u64, parents: Some((pa.id, pb.id)),
+                        generation: pa.generation.max(pb.generation) + 1, birth_tick: now, age: 0,
+                        pos: self.agents[i].pos, energy: 60.0, reproduce_cooldown: cfg.cooldown_time,
+                        speed_gen: cs.clamp(0.08, 0.3), vision_gen: cv.clamp(8.0, 30.0), lifespan_gen: cl.clamp(1000.0, 30000.0),
+                        resistance_gen: cr.clamp(0.0, 1.0), exposure: 0, kind: AgentKind::Valkarai,
+                        health: ch, max_health: ch, damage: cd.clamp(2.0, 30.0),
+                        state: AgentState::Wandering, target: None, path: Vec::new(), path_goal: None,
+                    });
+                    break;
+                }
+            }
+        }
+        // Старіння і смерті; новонароджені ще не прожили жодного тіку
+        let mut alive = Vec::with_capacity(self.agents.len());
+        for a in &mut self.agents {
+            let cause = if a.health <= 0.0 {
+                Some(DeathCause::Combat) // вже пораховано в combat::resolve
+            } else if a.energy <= 0.0 {
+                match a.kind {
+                    AgentKind::Valkarai => events.starvation_deaths += 1,
+                    AgentKind::Zombie => events.zombies_starved += 1,
+                }
+                let (x, y) = cell_of(a.pos);
+                self.cells[x][y].starvation_deaths += 1;
+                Some(DeathCause::Starvation)
+            } else {
+                a.age += 1;
+                match a.kind {
+                    AgentKind::Valkarai if a.age as f32 >= a.lifespan_gen => {
+                        events.old_age_deaths += 1;
+                        Some(DeathCause::OldAge)
+                    }
+                    AgentKind::Zombie => {
+                        a.health -= cfg.zombie_decay;
+                        if a.health <= 0.0 { events.decay_deaths += 1; Some(DeathCause::Decay) } else { None }
+                    }
+                    AgentKind::Valkarai => None,
+                }
+            };
+            if let Some(cause) = cause { self.lineage.record_death(a.id, now, cause); }
+            alive.push(cause.is_none());
+        }
+        let mut keep = alive.into_iter();
+        self.agents.retain(|_| keep.next().unwrap());
+        events.births = newborns.len() as u32;
+        for a in &newborns { self.lineage.record_birth(a); }
+        self.agents.append(&mut newborns);
+        self.events = events;
+    }
+
+    /// Лічильники народжень, заражень і смертей за останній `update`.
+    pub fn last_events(&self) -> TickEvents { self.events }
+
+    pub fn config(&self) -> &SimConfig { &self.config }
+
+    /// Родовід усіх агентів, включно з мертвими.
+    pub fn lineage(&self) -> &Lineage { &self.lineage }
+
+    pub fn agent_by_id(&self, id: u64) -> Option<&Agent> { self.agents.iter().find(|a| a.id == id) }
+
+    pub fn biomes(&self) -> &BiomeSet { &self.biomes }
+
+    /// Зерно, з якого створено світ.
+    pub fn seed(&self) -> u64 { self.seed }
+
+    /// Скільки тіків минуло від створення світу.
+    pub fn tick(&self) -> u64 { self.tick }
+
+    /// Розмір сітки по кожній осі.
+    pub fn size(&self) -> usize { self.cells.len() }
+
+    pub fn agents(&self) -> &[Agent] { &self.agents }
+
+    pub fn cells(&self) -> &[Vec<Cell>] { &self.cells }
+
+    pub fn cell(&self, x: usize, y: usize) -> Option<&Cell> { self.cells.get(x)?.get(y) }
+
+    pub fn count(&self, kind: AgentKind) -> usize { self.agents.iter().filter(|a| a.kind == kind).count() }
+
+    pub fn agents_of(&self, kind: AgentKind) -> impl Iterator<Item = &Agent> { self.agents.iter().filter(move |a| a.kind == kind) }
+}
+
+impl Default for World {
+    fn default() -> Self { Self::new() }
+}
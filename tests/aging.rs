@@ -0,0 +1,57 @@
+use quadrisrah_sim::{AgentKind, AgentState, DeathCause, SimConfig, World};
+
+#[test]
+fn age_advances_once_per_tick() {
+    let mut world = World::with_seed(12);
+    let before: Vec<(u64, u64)> = world.agents().iter().map(|a| (a.id, a.age)).collect();
+    for _ in 0..10 { world.update(); }
+    for (id, age) in before {
+        if let Some(a) = world.agent_by_id(id) { assert_eq!(a.age, age + 10); }
+    }
+}
+
+#[test]
+fn fertility_and_metabolism_follow_age() {
+    let world = World::with_seed(12);
+    let mut a = world.agents_of(AgentKind::Valkarai).next().unwrap().clone();
+    a.lifespan_gen = 1000.0;
+    a.age = 100;
+    assert!(!a.is_fertile());
+    assert!(a.metabolism() < 1.0);
+    a.age = 500;
+    assert!(a.is_fertile());
+    assert_eq!(a.metabolism(), 1.0);
+    a.age = 900;
+    assert!(!a.is_fertile());
+    assert!(a.metabolism() > 1.0 && a.metabolism() <= 1.5);
+}
+
+#[test]
+fn zombies_decay_to_death() {
+    let config = SimConfig { zombie_decay: 5.0, ..SimConfig::default() };
+    let mut world = World::with_config(config, 12);
+    let zombie = world.agents_of(AgentKind::Zombie).next().unwrap().id;
+    let mut decayed = 0;
+    for _ in 0..100 {
+        world.update();
+        decayed += world.last_events().decay_deaths;
+    }
+    assert!(decayed >= 1);
+    assert_eq!(world.lineage().get(zombie).unwrap().death.map(|d| d.1), Some(DeathCause::Decay));
+}
+
+#[test]
+fn immature_agents_do_not_seek_mates() {
+    let mut world = World::with_seed(1);
+    let threshold = world.config().reproduction_threshold;
+    let mut eager_young = 0;
+    for _ in 0..800 {
+        world.update();
+        for a in world.agents_of(AgentKind::Valkarai) {
+            if a.is_fertile() { continue; }
+            assert_ne!(a.state, AgentState::SeekingMate, "agent {} aged {} seeks a mate", a.id, a.age);
+            if a.energy > threshold && a.reproduce_cooldown == 0.0 { eager_young += 1; }
+        }
+    }
+    assert!(eager_young > 0, "no well-fed immature agents to check");
+}
@@ -0,0 +1,80 @@
+use quadrisrah_sim::{vec2, AgentState, BiomeSet, Passability, SimConfig, Terrain, World};
+
+fn continental(seed: u64) -> World {
+    let cfg = SimConfig { grid_size: 100, biome_set: "continental".into(), ..SimConfig::default() };
+    cfg.validate().unwrap();
+    World::with_config(cfg, seed)
+}
+
+#[test]
+fn presets_are_valid() {
+    for name in BiomeSet::PRESETS {
+        BiomeSet::preset(name).unwrap().validate().unwrap();
+    }
+    assert_eq!(BiomeSet::tundra().biomes.len(), 3);
+    assert_eq!(BiomeSet::continental().biomes.len(), 5);
+}
+
+#[test]
+fn continental_world_uses_its_own_biomes() {
+    let world = continental(3);
+    let set = world.biomes();
+    assert!(world.cells().iter().flatten().all(|c| set.contains(c.terrain)));
+    assert!(world.cells().iter().flatten().any(|c| c.terrain == Terrain::Ocean));
+}
+
+#[test]
+fn tundra_lies_in_the_cold() {
+    let world = World::with_seed(7);
+    let mean = |inside: bool| {
+        let temps: Vec<f32> = world.cells().iter().flatten().filter(|c| (c.terrain == Terrain::Tundra) == inside).map(|c| c.temperature).collect();
+        temps.iter().sum::<f32>() / temps.len() as f32
+    };
+    assert!(mean(true) + 0.1 < mean(false), "tundra {} vs the rest {}", mean(true), mean(false));
+}
+
+#[test]
+fn nobody_walks_on_water() {
+    let mut world = continental(6);
+    for _ in 0..400 {
+        world.update();
+        for a in world.agents() {
+            let cell = world.cell(a.pos.x as usize, a.pos.y as usize).unwrap();
+            assert_ne!(world.biomes().get(cell.terrain).passable, Passability::Wall, "agent at {:?}", a.pos);
+        }
+    }
+}
+
+#[test]
+fn cells_keep_the_climate_their_biome_came_from() {
+    let world = continental(4);
+    let set = world.biomes();
+    for c in world.cells().iter().flatten() {
+        assert!((-1.0..=1.0).contains(&c.elevation));
+        assert!((0.0..=1.0).contains(&c.temperature) && (0.0..=1.0).contains(&c.moisture));
+        assert_eq!(set.classify(c.elevation, c.temperature, c.moisture), c.terrain);
+    }
+    // Океан — найнижчі клітинки
+    let ocean = world.cells().iter().flatten().filter(|c| c.terrain == Terrain::Ocean);
+    assert!(ocean.clone().count() > 0 && ocean.clone().all(|c| c.elevation <= -0.15));
+}
+
+#[test]
+fn hungry_valkarai_forage_in_the_desert() {
+    // Поріг голоду вище стартової енергії: новий Валкарай одразу голодний
+    let cfg = SimConfig { grid_size: 100, biome_set: "continental".into(), dire_energy: 150.0, ..SimConfig::default() };
+    let mut world = World::with_config(cfg, 5);
+    world.erase_agents(vec2(50.0, 50.0), 100.0);
+    world.paint_terrain(vec2(30.5, 30.5), 6.0, Terrain::Plains);
+    world.clear_food(vec2(30.5, 30.5), 10.0);
+    world.paint_terrain(vec2(32.5, 30.5), 0.0, Terrain::Desert);
+    // Пустеля за дві клітинки: з food_score = 3 вона ще в межах зору
+    // Рослини лише наближаються до ємності, тож повної пустельної клітинки не буває
+    let capacity = world.biomes().get(Terrain::Desert).capacity;
+    world.add_food(vec2(32.5, 30.5), 0.0, 0.99 * capacity);
+    let id = world.spawn_valkarai(vec2(30.5, 30.5), 0.2, 8.0, 0.0).unwrap();
+    world.update();
+    let a = world.agent_by_id(id).unwrap();
+    assert_eq!(a.state, AgentState::SeekingFood);
+    assert_eq!(a.target, Some(vec2(32.5, 30.5)));
+}
@@ -0,0 +1,102 @@
+use quadrisrah_sim::{ConfigError, SimConfig, World};
+
+#[test]
+fn defaults_are_valid() {
+    SimConfig::default().validate().unwrap();
+}
+
+#[test]
+fn example_file_matches_defaults() {
+    let cfg = SimConfig::load(concat!(env!("CARGO_MANIFEST_DIR"), "/sim.example.toml")).unwrap();
+    assert_eq!(cfg, SimConfig::default());
+}
+
+#[test]
+fn partial_file_keeps_other_defaults() {
+    let cfg = SimConfig::from_toml_str("grid_size = 80\ninitial_valkarai = 10").unwrap();
+    assert_eq!(cfg.grid_size, 80);
+    assert_eq!(cfg.initial_valkarai, 10);
+    assert_eq!(cfg.mating_distance, SimConfig::default().mating_distance);
+}
+
+#[test]
+fn unknown_key_is_rejected() {
+    let err = SimConfig::from_toml_str("grid_sise = 80").unwrap_err();
+    assert!(matches!(err, ConfigError::Parse(_)));
+    assert!(err.to_string().contains("grid_sise"), "{}", err);
+}
+
+#[test]
+fn out_of_range_value_is_rejected() {
+    let err = SimConfig::from_json_str(r#"{ "combat_rate": 15.0 }"#).unwrap_err();
+    assert!(matches!(err, ConfigError::Invalid(_)));
+    assert!(err.to_string().contains("combat_rate"), "{}", err);
+}
+
+#[test]
+fn unknown_biome_set_is_rejected() {
+    let err = SimConfig::from_toml_str("biome_set = \"jungle\"").unwrap_err();
+    assert!(err.to_string().contains("jungle"), "{}", err);
+}
+
+#[test]
+fn custom_biomes_replace_preset() {
+    let cfg = SimConfig::from_toml_str(r#"
+        grid_size = 40
+        [[biomes]]
+        terrain = "Plains"
+        elevation = [-0.2, 1.0]
+        color = [0.4, 0.7, 0.2]
+        speed = 1.0
+        capacity = 3.0
+        growth = 0.003
+        passable = "Open"
+        food_score = 1.0
+        [[biomes]]
+        terrain = "Ocean"
+        color = [0.0, 0.4, 0.9]
+        speed = 0.1
+        capacity = 0.0
+        growth = 0.0
+        passable = "Wall"
+        food_score = 1.0
+    "#).unwrap();
+    let set = cfg.biome_set();
+    assert_eq!(set.biomes.len(), 2);
+    let world = World::with_config(cfg, 1);
+    assert!(world.cells().iter().flatten().all(|c| set.contains(c.terrain)));
+}
+
+#[test]
+fn inverted_climate_range_is_rejected() {
+    let err = SimConfig::from_toml_str(r#"
+        [[biomes]]
+        terrain = "Ocean"
+        elevation = [-1.0, -0.2]
+        color = [0.0, 0.4, 0.9]
+        speed = 0.1
+        capacity = 0.0
+        growth = 0.0
+        passable = "Open"
+        food_score = 1.0
+        [[biomes]]
+        terrain = "Plains"
+        moisture = [0.8, 0.3]
+        color = [0.4, 0.7, 0.2]
+        speed = 1.0
+        capacity = 3.0
+        growth = 0.003
+        passable = "Open"
+        food_score = 1.0
+    "#).unwrap_err();
+    assert!(err.to_string().contains("Plains") || err.to_string().contains("plains"), "{}", err);
+}
+
+#[test]
+fn world_uses_config() {
+    let cfg = SimConfig::from_toml_str("grid_size = 60\ninitial_valkarai = 5").unwrap();
+    let mut world = World::with_config(cfg, 3);
+    assert_eq!(world.size(), 60);
+    assert_eq!(world.agents().len(), 6);
+    for _ in 0..100 { world.update(); }
+}
@@ -0,0 +1,57 @@
+use quadrisrah_sim::{Agent, Cell, LineageRecord, World};
+
+fn run(seed: u64, ticks: u64) -> World {
+    let mut world = World::with_seed(seed);
+    for _ in 0..ticks { world.update(); }
+    world
+}
+
+/// Побітові відбитки f32-полів: `==` вважає `0.0` і `-0.0` рівними, а тут потрібен точний збіг.
+fn agent_bits(a: &Agent) -> Vec<u32> {
+    let target = a.target.map_or([u32::MAX; 2], |t| [t.x.to_bits(), t.y.to_bits()]);
+    let mut bits = vec![
+        a.pos.x.to_bits(), a.pos.y.to_bits(), a.energy.to_bits(), a.reproduce_cooldown.to_bits(),
+        a.speed_gen.to_bits(), a.vision_gen.to_bits(), a.lifespan_gen.to_bits(), a.resistance_gen.to_bits(),
+        a.health.to_bits(), a.max_health.to_bits(), a.damage.to_bits(),
+    ];
+    bits.extend(target);
+    bits
+}
+
+fn record_bits(r: &LineageRecord) -> [u32; 6] {
+    [r.speed_gen.to_bits(), r.vision_gen.to_bits(), r.max_health.to_bits(), r.damage.to_bits(), r.lifespan_gen.to_bits(), r.resistance_gen.to_bits()]
+}
+
+fn cell_bits(c: &Cell) -> [u32; 5] {
+    [c.food_level.to_bits(), c.vigor.to_bits(), c.elevation.to_bits(), c.temperature.to_bits(), c.moisture.to_bits()]
+}
+
+#[test]
+fn same_seed_gives_identical_agents() {
+    let a = run(42, 300);
+    let b = run(42, 300);
+    assert_eq!(a.tick(), b.tick());
+    // Порівнюємо агентів цілком, тож нові поля теж під перевіркою, а дробові — ще й побітово
+    assert_eq!(a.agents(), b.agents());
+    assert_eq!(a.agents().iter().map(agent_bits).collect::<Vec<_>>(), b.agents().iter().map(agent_bits).collect::<Vec<_>>());
+    assert_eq!(a.lineage(), b.lineage());
+    assert_eq!(a.lineage().records().iter().map(record_bits).collect::<Vec<_>>(), b.lineage().records().iter().map(record_bits).collect::<Vec<_>>());
+}
+
+#[test]
+fn same_seed_gives_identical_terrain() {
+    // Після кількох кроків росту: їжа й сила рослин теж мають збігтися
+    let a = run(7, 100);
+    let b = run(7, 100);
+    for (ca, cb) in a.cells().iter().flatten().zip(b.cells().iter().flatten()) {
+        assert_eq!(ca.terrain, cb.terrain);
+        assert_eq!(cell_bits(ca), cell_bits(cb));
+    }
+}
+
+#[test]
+fn different_seeds_diverge() {
+    let a = run(1, 50);
+    let b = run(2, 50);
+    assert_ne!(a.agents(), b.agents());
+}
@@ -0,0 +1,66 @@
+use quadrisrah_sim::{vec2, AgentKind, DeathCause, SimConfig, Terrain, World};
+
+#[test]
+fn edits_survive_a_snapshot() {
+    let mut world = World::with_seed(3);
+    let painted = world.paint_terrain(vec2(100.0, 100.0), 4.0, Terrain::Forest);
+    assert!(painted > 0);
+    let before = world.cell(100, 100).unwrap().food_level;
+    world.add_food(vec2(100.0, 100.0), 2.0, 50.0);
+    let id = world.spawn_valkarai(vec2(100.5, 100.5), 0.25, 22.0, 0.4).unwrap();
+
+    let loaded = World::from_snapshot_str(&world.to_snapshot_string()).unwrap();
+    assert_eq!(loaded.cell(100, 100).unwrap().terrain, Terrain::Forest);
+    assert_eq!(loaded.cell(100, 100).unwrap().food_level, before + 50.0);
+    let a = loaded.agent_by_id(id).unwrap();
+    assert_eq!((a.speed_gen, a.vision_gen, a.kind), (0.25, 22.0, AgentKind::Valkarai));
+    assert_eq!(loaded.lineage().get(id).unwrap().birth_tick, world.tick());
+}
+
+#[test]
+fn clear_food_empties_the_brush() {
+    let mut world = World::with_seed(3);
+    world.add_food(vec2(20.0, 20.0), 3.0, 80.0);
+    world.clear_food(vec2(20.0, 20.0), 3.0);
+    assert_eq!(world.cell(20, 20).unwrap().food_level, 0.0);
+}
+
+#[test]
+fn eraser_removes_agents_and_records_it() {
+    let mut world = World::with_seed(3);
+    world.paint_terrain(vec2(10.0, 10.0), 3.0, Terrain::Forest);
+    let z = world.spawn_zombie(vec2(10.0, 10.0)).unwrap();
+    let before = world.agents().len();
+    assert!(world.erase_agents(vec2(10.0, 10.0), 1.0) >= 1);
+    assert!(world.agent_by_id(z).is_none());
+    assert!(world.agents().len() < before);
+    assert_eq!(world.lineage().get(z).unwrap().death.map(|d| d.1), Some(DeathCause::Removed));
+    for _ in 0..20 { world.update(); }
+}
+
+#[test]
+fn spawning_into_a_wall_is_refused() {
+    let config = SimConfig { biome_set: "continental".into(), ..SimConfig::default() };
+    let mut world = World::with_config(config, 3);
+    world.paint_terrain(vec2(30.0, 30.0), 2.0, Terrain::Ocean);
+    assert_eq!(world.spawn_zombie(vec2(30.0, 30.0)), None);
+    assert_eq!(world.spawn_valkarai(vec2(-1.0, 5.0), 0.2, 12.0, 0.0), None);
+}
+
+#[test]
+fn wall_paint_leaves_occupied_cells_open() {
+    let config = SimConfig { biome_set: "continental".into(), ..SimConfig::default() };
+    let mut world = World::with_config(config, 3);
+    world.erase_agents(vec2(30.0, 30.0), 6.0);
+    world.paint_terrain(vec2(30.5, 30.5), 3.0, Terrain::Plains);
+    let id = world.spawn_valkarai(vec2(30.5, 30.5), 0.2, 12.0, 0.0).unwrap();
+    let painted = world.paint_terrain(vec2(30.5, 30.5), 3.0, Terrain::Ocean);
+    assert!(painted > 0);
+    assert_eq!(world.cell(30, 30).unwrap().terrain, Terrain::Plains);
+    let ocean = world.cell(31, 30).unwrap();
+    assert_eq!(ocean.terrain, Terrain::Ocean);
+    // Клімат перефарбованої клітинки — середина ніші океану
+    assert_eq!((ocean.elevation, ocean.temperature, ocean.moisture), world.biomes().get(Terrain::Ocean).niche_center());
+    for _ in 0..20 { world.update(); }
+    assert!(world.agent_by_id(id).unwrap().pos.distance(vec2(30.5, 30.5)) < 1.5);
+}
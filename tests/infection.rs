@@ -0,0 +1,44 @@
+use quadrisrah_sim::{vec2, AgentKind, SimConfig, World};
+
+/// Один Валкарай поруч із зомбі, без бою і без гарантованого зараження від ран.
+fn duel(infection_chance: f32, resistance_gen: f32) -> (World, u64) {
+    let config = SimConfig {
+        initial_valkarai: 0, combat_rate: 0.0, infection_health_fraction: 0.0, infection_chance, ..SimConfig::default()
+    };
+    let mut world = World::with_config(config, 21);
+    world.spawn_zombie(vec2(150.0, 150.0)).unwrap();
+    let v = world.spawn_valkarai(vec2(150.5, 150.0), 0.08, 8.0, resistance_gen).unwrap();
+    (world, v)
+}
+
+fn infected_within(world: &mut World, v: u64, ticks: u32) -> bool {
+    for _ in 0..ticks {
+        world.update();
+        match world.agent_by_id(v) {
+            Some(a) if a.kind == AgentKind::Zombie => return true,
+            Some(_) => {}
+            None => return false,
+        }
+    }
+    false
+}
+
+#[test]
+fn certain_chance_infects_on_first_contact() {
+    let (mut world, v) = duel(1.0, 0.0);
+    assert!(infected_within(&mut world, v, 3));
+    assert!(world.lineage().get(v).unwrap().infected_tick.is_some());
+}
+
+#[test]
+fn full_resistance_is_immune() {
+    let (mut world, v) = duel(1.0, 1.0);
+    assert!(!infected_within(&mut world, v, 50));
+    assert!(world.agent_by_id(v).unwrap().exposure > 0);
+}
+
+#[test]
+fn zero_chance_never_infects() {
+    let (mut world, v) = duel(0.0, 0.0);
+    assert!(!infected_within(&mut world, v, 50));
+}
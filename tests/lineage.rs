@@ -0,0 +1,55 @@
+use quadrisrah_sim::{SimConfig, World};
+
+fn busy_world() -> World {
+    let cfg = SimConfig { grid_size: 60, initial_valkarai: 60, ..SimConfig::default() };
+    let mut world = World::with_config(cfg, 21);
+    for _ in 0..300 { world.update(); }
+    world
+}
+
+#[test]
+fn ids_are_unique_and_match_lineage() {
+    let world = busy_world();
+    let mut ids: Vec<u64> = world.agents().iter().map(|a| a.id).collect();
+    ids.sort_unstable();
+    ids.dedup();
+    assert_eq!(ids.len(), world.agents().len());
+    for a in world.agents() {
+        let r = world.lineage().get(a.id).unwrap();
+        assert_eq!((r.generation, r.birth_tick, r.parents), (a.generation, a.birth_tick, a.parents));
+        assert!(r.death.is_none());
+    }
+}
+
+#[test]
+fn children_come_after_their_parents() {
+    let world = busy_world();
+    let records = world.lineage().records();
+    assert!(records.iter().any(|r| r.parents.is_some()), "no births in the test run");
+    for r in records {
+        if let Some((a, b)) = r.parents {
+            let (pa, pb) = (&records[a as usize], &records[b as usize]);
+            assert_eq!(r.generation, pa.generation.max(pb.generation) + 1);
+            assert!(pa.birth_tick < r.birth_tick && pb.birth_tick < r.birth_tick);
+        }
+    }
+}
+
+#[test]
+fn dead_agents_stay_in_lineage() {
+    let world = busy_world();
+    let dead = world.lineage().records().iter().filter(|r| r.death.is_some()).count();
+    assert!(dead > 0);
+    assert_eq!(world.lineage().records().len(), world.agents().len() + dead);
+}
+
+#[test]
+fn graphml_has_a_node_per_record() {
+    let world = busy_world();
+    let mut buf = Vec::new();
+    world.lineage().write_graphml(&mut buf).unwrap();
+    let text = String::from_utf8(buf).unwrap();
+    assert_eq!(text.matches("<node ").count(), world.lineage().records().len());
+    let births = world.lineage().records().iter().filter(|r| r.parents.is_some()).count();
+    assert_eq!(text.matches("<edge ").count(), births * 2);
+}
@@ -0,0 +1,152 @@
+use quadrisrah_sim::{AgentKind, BiomeSet, MapError, SimConfig, Terrain, Vec2, World};
+use std::path::{Path, PathBuf};
+
+/// Тимчасовий PNG, який прибирається разом зі змінною, навіть якщо тест упав.
+struct TempPng(PathBuf);
+
+impl TempPng {
+    fn new(name: &str) -> Self {
+        TempPng(std::env::temp_dir().join(format!("quadrisrah_test_{}_{}.png", std::process::id(), name)))
+    }
+}
+
+impl AsRef<Path> for TempPng {
+    fn as_ref(&self) -> &Path { &self.0 }
+}
+
+impl Drop for TempPng {
+    fn drop(&mut self) { let _ = std::fs::remove_file(&self.0); }
+}
+
+fn write_png(name: &str, width: u32, height: u32, color: png::ColorType, data: &[u8]) -> TempPng {
+    let png = TempPng::new(name);
+    let mut encoder = png::Encoder::new(std::fs::File::create(&png).unwrap(), width, height);
+    encoder.set_color(color);
+    encoder.set_depth(png::BitDepth::Eight);
+    encoder.write_header().unwrap().write_image_data(data).unwrap();
+    png
+}
+
+fn rgb(terrain: Terrain, biomes: &BiomeSet) -> [u8; 3] {
+    biomes.get(terrain).color.map(|c| (c * 255.0).round() as u8)
+}
+
+fn continental() -> SimConfig {
+    SimConfig { biome_set: "continental".into(), initial_valkarai: 5, ..SimConfig::default() }
+}
+
+#[test]
+fn terrain_and_food_come_from_the_images() {
+    let biomes = BiomeSet::continental();
+    let size = 12;
+    // Лівий стовпчик — океан, решта — рівнини; один піксель трохи "шумний"
+    let mut terrain = Vec::new();
+    for _ in 0..size {
+        for x in 0..size {
+            terrain.extend(rgb(if x == 0 { Terrain::Ocean } else { Terrain::Plains }, &biomes));
+        }
+    }
+    terrain[3 * (5 * size + 7)] += 10;
+    let mut food = vec![0u8; size * size];
+    food[2 * size + 3] = 255;
+    let terrain_png = write_png("terrain", size as u32, size as u32, png::ColorType::Rgb, &terrain);
+    let food_png = write_png("food", size as u32, size as u32, png::ColorType::Grayscale, &food);
+
+    let world = World::from_map_png(continental(), 3, &terrain_png, Some(food_png.as_ref())).unwrap();
+    assert_eq!(world.size(), size);
+    assert_eq!(world.config().grid_size, size);
+    for y in 0..size {
+        assert_eq!(world.cell(0, y).unwrap().terrain, Terrain::Ocean);
+        assert_eq!(world.cell(1, y).unwrap().terrain, Terrain::Plains);
+    }
+    assert_eq!(world.cell(7, 5).unwrap().terrain, Terrain::Plains);
+    assert_eq!(world.cell(3, 2).unwrap().food_level, biomes.get(Terrain::Plains).capacity);
+    assert_eq!(world.cell(2, 3).unwrap().food_level, 0.0);
+    assert!(world.agents().iter().all(|a| a.pos.x >= 1.0), "nobody starts in the ocean");
+
+    // Та сама карта й зерно дають той самий світ
+    let again = World::from_map_png(continental(), 3, &terrain_png, Some(food_png.as_ref())).unwrap();
+    assert_eq!(again.agents(), world.agents());
+}
+
+#[test]
+fn unknown_color_is_reported_with_its_pixel() {
+    let biomes = BiomeSet::continental();
+    let mut terrain: Vec<u8> = (0..100).flat_map(|_| rgb(Terrain::Plains, &biomes)).collect();
+    terrain[3 * (4 * 10 + 6)..][..3].copy_from_slice(&[255, 0, 255]);
+    let path = write_png("unknown", 10, 10, png::ColorType::Rgb, &terrain);
+    match World::from_map_png(continental(), 1, &path, None) {
+        Err(MapError::UnknownColor { x: 6, y: 4, rgb: [255, 0, 255] }) => {}
+        other => panic!("expected unknown color error, got {:?}", other.err()),
+    }
+    // Рівнини континенту — не тундра
+    let tundra = SimConfig { biome_set: "tundra".into(), ..continental() };
+    let path = write_png("tundra", 10, 10, png::ColorType::Rgb, &(0..100).flat_map(|_| rgb(Terrain::Ocean, &biomes)).collect::<Vec<_>>());
+    assert!(matches!(World::from_map_png(tundra, 1, &path, None), Err(MapError::UnknownColor { .. })));
+}
+
+#[test]
+fn bad_sizes_are_rejected() {
+    let biomes = BiomeSet::continental();
+    let plains = |n: usize| (0..n).flat_map(|_| rgb(Terrain::Plains, &biomes)).collect::<Vec<_>>();
+    let wide = write_png("wide", 12, 10, png::ColorType::Rgb, &plains(120));
+    assert!(matches!(World::from_map_png(continental(), 1, &wide, None), Err(MapError::NotSquare { width: 12, height: 10 })));
+
+    let tiny = write_png("tiny", 4, 4, png::ColorType::Rgb, &plains(16));
+    assert!(matches!(World::from_map_png(continental(), 1, &tiny, None), Err(MapError::Config(_))));
+
+    let terrain = write_png("sized", 10, 10, png::ColorType::Rgb, &plains(100));
+    let food = write_png("small_food", 11, 11, png::ColorType::Grayscale, &[0; 121]);
+    assert!(matches!(
+        World::from_map_png(continental(), 1, &terrain, Some(food.as_ref())),
+        Err(MapError::SizeMismatch { terrain: 10, food: 11 })
+    ));
+}
+
+fn decode(bytes: &[u8]) -> (u32, Vec<u8>) {
+    let mut reader = png::Decoder::new(bytes).read_info().unwrap();
+    let mut buf = vec![0; reader.output_buffer_size()];
+    let info = reader.next_frame(&mut buf).unwrap();
+    assert_eq!(info.color_type, png::ColorType::Rgb);
+    assert_eq!(info.width, info.height);
+    (info.width, buf)
+}
+
+#[test]
+fn exported_map_shows_terrain_and_agents() {
+    let mut world = World::with_seed(5);
+    // Клітинка без рослин і агентів поблизу — чистий колір біому
+    let (x, y) = (0..world.size()).flat_map(|x| (0..world.size()).map(move |y| (x, y)))
+        .find(|&(x, y)| world.agents().iter().all(|a| a.pos.distance(Vec2::new(x as f32 + 0.5, y as f32 + 0.5)) > 3.0))
+        .unwrap();
+    world.clear_food(Vec2::new(x as f32 + 0.5, y as f32 + 0.5), 0.0);
+    let mut bytes = Vec::new();
+    world.write_map_png(&mut bytes, 3).unwrap();
+    let (side, pixels) = decode(&bytes);
+    assert_eq!(side as usize, world.size() * 3);
+    let pixel = |x: usize, y: usize| -> [u8; 3] { pixels[(y * side as usize + x) * 3..][..3].try_into().unwrap() };
+
+    let zombie = world.agents().iter().find(|a| a.kind == AgentKind::Zombie).unwrap();
+    assert_eq!(pixel((zombie.pos.x * 3.0) as usize, (zombie.pos.y * 3.0) as usize), [0, 0, 0]);
+    assert_eq!(pixel(x * 3 + 1, y * 3 + 1), rgb(world.cell(x, y).unwrap().terrain, world.biomes()));
+    // Сусідня клітинка з рослинами підфарбована
+    assert_ne!(pixel(x * 3 + 4, y * 3 + 1), rgb(world.cell(x + 1, y).unwrap().terrain, world.biomes()));
+}
+
+#[test]
+fn bare_exported_map_reads_back_as_the_same_terrain() {
+    let mut world = World::with_config(SimConfig { grid_size: 40, ..continental() }, 8);
+    let size = world.size() as f32;
+    world.erase_agents(Vec2::splat(size / 2.0), size);
+    world.clear_food(Vec2::splat(size / 2.0), size);
+    let path = TempPng::new("roundtrip");
+    world.save_map_png(&path, 1).unwrap();
+
+    let loaded = World::from_map_png(continental(), 8, &path, None).unwrap();
+    assert_eq!(loaded.size(), world.size());
+    for x in 0..world.size() {
+        for y in 0..world.size() {
+            assert_eq!(loaded.cell(x, y).unwrap().terrain, world.cell(x, y).unwrap().terrain);
+        }
+    }
+}
@@ -0,0 +1,45 @@
+use quadrisrah_sim::path::find_path;
+use quadrisrah_sim::{vec2, SimConfig, Terrain, World};
+
+/// Континентальний світ, де все — рівнина, крім того, що домалює тест.
+fn flat_world() -> World {
+    let config = SimConfig { grid_size: 40, initial_valkarai: 0, biome_set: "continental".into(), ..SimConfig::default() };
+    let mut world = World::with_config(config, 1);
+    world.paint_terrain(vec2(20.0, 20.0), 40.0, Terrain::Plains);
+    world
+}
+
+#[test]
+fn open_ground_gives_a_straight_path() {
+    let world = flat_world();
+    let path = find_path(world.cells(), world.biomes(), (5, 5), (15, 9), false).unwrap();
+    assert_eq!(path.len(), 10);
+    assert_eq!(path[0], (15, 9));
+}
+
+#[test]
+fn path_goes_around_a_wall() {
+    let mut world = flat_world();
+    for y in 5..=30 { world.paint_terrain(vec2(20.5, y as f32 + 0.5), 0.0, Terrain::Ocean); }
+    let path = find_path(world.cells(), world.biomes(), (15, 15), (25, 15), false).unwrap();
+    assert!(path.iter().all(|&(x, y)| world.cell(x, y).unwrap().terrain != Terrain::Ocean));
+    assert!(path.len() > 10);
+}
+
+#[test]
+fn desert_is_only_open_when_dire() {
+    let mut world = flat_world();
+    world.paint_terrain(vec2(20.0, 20.0), 4.0, Terrain::Desert);
+    let through = |p: &[(usize, usize)]| p.iter().any(|&(x, y)| world.cell(x, y).unwrap().terrain == Terrain::Desert);
+    assert!(!through(&find_path(world.cells(), world.biomes(), (10, 20), (30, 20), false).unwrap()));
+    assert!(find_path(world.cells(), world.biomes(), (10, 20), (20, 20), false).is_none());
+    assert!(find_path(world.cells(), world.biomes(), (10, 20), (20, 20), true).is_some());
+}
+
+#[test]
+fn enclosed_goal_is_unreachable() {
+    let mut world = flat_world();
+    world.paint_terrain(vec2(20.5, 20.5), 3.0, Terrain::Ocean);
+    world.paint_terrain(vec2(20.5, 20.5), 0.0, Terrain::Plains);
+    assert!(find_path(world.cells(), world.biomes(), (5, 5), (20, 20), true).is_none());
+}
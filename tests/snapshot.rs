@@ -0,0 +1,38 @@
+use quadrisrah_sim::{SnapshotError, World, SNAPSHOT_VERSION};
+
+#[test]
+fn resumed_run_matches_uninterrupted_run() {
+    let mut world = World::with_seed(99);
+    for _ in 0..150 { world.update(); }
+    let mut resumed = World::from_snapshot_str(&world.to_snapshot_string()).unwrap();
+    assert_eq!(resumed.tick(), 150);
+    assert_eq!(resumed.agents(), world.agents());
+    for _ in 0..150 {
+        world.update();
+        resumed.update();
+    }
+    assert_eq!(resumed.agents(), world.agents());
+}
+
+#[test]
+fn other_version_is_rejected() {
+    let world = World::with_seed(1);
+    let text = world.to_snapshot_string().replacen(
+        &format!("\"version\":{}", SNAPSHOT_VERSION),
+        &format!("\"version\":{}", SNAPSHOT_VERSION + 1),
+        1,
+    );
+    match World::from_snapshot_str(&text) {
+        Err(SnapshotError::UnsupportedVersion { found, expected }) => {
+            assert_eq!(found, SNAPSHOT_VERSION + 1);
+            assert_eq!(expected, SNAPSHOT_VERSION);
+        }
+        other => panic!("expected version error, got {:?}", other.err()),
+    }
+}
+
+#[test]
+fn foreign_file_is_rejected() {
+    assert!(matches!(World::from_snapshot_str("{\"grid_size\": 10}"), Err(SnapshotError::NotASnapshot)));
+    assert!(matches!(World::from_snapshot_str("not json"), Err(SnapshotError::NotASnapshot)));
+}
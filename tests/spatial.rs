@@ -0,0 +1,51 @@
+use quadrisrah_sim::spatial::SpatialGrid;
+use quadrisrah_sim::{vec2, Agent, AgentKind, AgentState};
+use rand::{Rng, SeedableRng};
+use rand_chacha::ChaCha8Rng;
+
+fn random_agents(rng: &mut ChaCha8Rng, n: usize, size: f32) -> Vec<Agent> {
+    (0..n).map(|i| Agent {
+        id: i as u64, parents: None, generation: 0, birth_tick: 0, age: 0,
+        pos: vec2(rng.gen_range(0.0..size), rng.gen_range(0.0..size)),
+        energy: 100.0, reproduce_cooldown: 0.0, speed_gen: 0.15, vision_gen: 15.0, lifespan_gen: 8000.0, resistance_gen: 0.0, exposure: 0,
+        kind: if i % 3 == 0 { AgentKind::Zombie } else { AgentKind::Valkarai },
+        health: 100.0, max_health: 100.0, damage: 10.0, state: AgentState::Wandering, target: None, path: Vec::new(), path_goal: None,
+    }).collect()
+}
+
+fn brute(agents: &[Agent], kind: AgentKind, p: quadrisrah_sim::Vec2, r: f32) -> Vec<usize> {
+    (0..agents.len()).filter(|&j| agents[j].kind == kind && p.distance(agents[j].pos) < r).collect()
+}
+
+#[test]
+fn query_matches_brute_force() {
+    let mut rng = ChaCha8Rng::seed_from_u64(11);
+    let agents = random_agents(&mut rng, 500, 100.0);
+    let mut grid = SpatialGrid::new(100);
+    grid.rebuild(&agents);
+    let mut out = Vec::new();
+    for _ in 0..200 {
+        let p = vec2(rng.gen_range(0.0..100.0), rng.gen_range(0.0..100.0));
+        let r = rng.gen_range(0.5..40.0);
+        for kind in [AgentKind::Valkarai, AgentKind::Zombie] {
+            grid.query(&agents, kind, p, r, &mut out);
+            assert_eq!(out, brute(&agents, kind, p, r));
+        }
+    }
+}
+
+#[test]
+fn update_tracks_moved_agents() {
+    let mut rng = ChaCha8Rng::seed_from_u64(12);
+    let mut agents = random_agents(&mut rng, 200, 100.0);
+    let mut grid = SpatialGrid::new(100);
+    grid.rebuild(&agents);
+    for (i, a) in agents.iter_mut().enumerate() {
+        a.pos = vec2(rng.gen_range(0.0..99.0), rng.gen_range(0.0..99.0));
+        grid.update(i, a.kind, a.pos);
+    }
+    let mut out = Vec::new();
+    let p = vec2(50.0, 50.0);
+    grid.query(&agents, AgentKind::Valkarai, p, 30.0, &mut out);
+    assert_eq!(out, brute(&agents, AgentKind::Valkarai, p, 30.0));
+}
@@ -0,0 +1,94 @@
+use quadrisrah_sim::{StatsRecorder, World};
+
+#[test]
+fn records_one_row_per_tick() {
+    let mut world = World::with_seed(4);
+    let mut stats = StatsRecorder::new();
+    for _ in 0..200 {
+        world.update();
+        stats.record(&world);
+    }
+    assert_eq!(stats.rows().len(), 200);
+    let last = stats.rows().last().unwrap();
+    assert_eq!(last.tick, 200);
+    assert_eq!(last.valkarai + last.zombies, world.agents().len());
+}
+
+#[test]
+fn population_change_is_explained_by_events() {
+    let mut world = World::with_seed(8);
+    let mut stats = StatsRecorder::new();
+    stats.record(&world);
+    for _ in 0..300 {
+        world.update();
+        stats.record(&world);
+    }
+    for w in stats.rows().windows(2) {
+        let (prev, cur) = (&w[0], &w[1]);
+        let expected = prev.valkarai as i64 + cur.births as i64 - cur.infections as i64
+            - cur.starvation_deaths as i64 - cur.old_age_deaths as i64 - cur.valkarai_killed as i64;
+        assert_eq!(cur.valkarai as i64, expected, "tick {}", cur.tick);
+        assert_eq!(cur.zombies as i64, prev.zombies as i64 + cur.infections as i64 - cur.zombies_killed as i64 - cur.decay_deaths as i64 - cur.zombies_starved as i64, "tick {}", cur.tick);
+    }
+}
+
+#[test]
+fn csv_rows_match_header_width() {
+    let mut world = World::with_seed(5);
+    let mut stats = StatsRecorder::new();
+    for _ in 0..20 {
+        world.update();
+        stats.record(&world);
+    }
+    let mut buf = Vec::new();
+    stats.write_csv(&mut buf).unwrap();
+    let text = String::from_utf8(buf).unwrap();
+    let mut lines = text.lines();
+    let width = lines.next().unwrap().split(',').count();
+    assert_eq!(lines.clone().count(), 20);
+    assert!(lines.all(|l| l.split(',').count() == width));
+}
+
+#[test]
+fn clear_starts_a_new_run() {
+    let mut world = World::with_seed(4);
+    let mut stats = StatsRecorder::new();
+    for _ in 0..50 {
+        world.update();
+        stats.record(&world);
+    }
+    // Інший світ того самого набору біомів: рядки першого не мають туди потрапити
+    let mut other = World::with_seed(5);
+    other.update();
+    stats.clear();
+    stats.record(&other);
+    assert_eq!(stats.rows().len(), 1);
+    assert_eq!(stats.rows()[0].tick, 1);
+}
+
+#[test]
+fn bounded_recorder_keeps_only_recent_ticks() {
+    let mut world = World::with_seed(4);
+    let mut stats = StatsRecorder::bounded(30);
+    for _ in 0..100 {
+        world.update();
+        stats.record(&world);
+    }
+    let ticks: Vec<u64> = stats.rows().iter().map(|r| r.tick).collect();
+    assert_eq!(ticks, (71..=100).collect::<Vec<_>>());
+}
+
+#[test]
+fn cell_counters_accumulate_events() {
+    let mut world = World::with_seed(8);
+    let (mut starved, mut infected) = (0u32, 0u32);
+    for _ in 0..300 {
+        world.update();
+        starved += world.last_events().starvation_deaths + world.last_events().zombies_starved;
+        infected += world.last_events().infections;
+    }
+    let cells = world.cells().iter().flatten();
+    let (cell_starved, cell_infected) = cells.fold((0, 0), |(s, i), c| (s + c.starvation_deaths, i + c.infections));
+    assert_eq!(cell_starved, starved);
+    assert_eq!(cell_infected, infected);
+}
@@ -0,0 +1,92 @@
+use quadrisrah_sim::vegetation::GROWTH_INTERVAL;
+use quadrisrah_sim::{vec2, SimConfig, Terrain, World};
+
+/// Світ без агентів: рослини ростуть самі по собі.
+fn empty_world(seed: u64) -> World {
+    let mut world = World::with_config(SimConfig { grid_size: 40, initial_valkarai: 0, ..SimConfig::default() }, seed);
+    world.erase_agents(vec2(20.0, 20.0), 100.0);
+    world
+}
+
+fn total_food(world: &World) -> f32 {
+    world.cells().iter().flatten().map(|c| c.food_level).sum()
+}
+
+#[test]
+fn plants_grow_toward_capacity_and_stop_there() {
+    let mut world = empty_world(2);
+    let start = total_food(&world);
+    for _ in 0..GROWTH_INTERVAL * 100 { world.update(); }
+    let grown = total_food(&world);
+    assert!(grown > start, "{} -> {}", start, grown);
+    for _ in 0..GROWTH_INTERVAL * 1000 { world.update(); }
+    for c in world.cells().iter().flatten() {
+        let capacity = world.biomes().get(c.terrain).capacity;
+        assert!(c.food_level <= capacity + 1e-3, "{} over capacity {}", c.food_level, capacity);
+        assert!(c.food_level > 0.9 * capacity, "{} far below capacity {}", c.food_level, capacity);
+    }
+}
+
+#[test]
+fn bare_cells_are_reseeded_only_from_neighbours() {
+    let mut world = empty_world(4);
+    world.clear_food(vec2(10.5, 10.5), 0.0);
+    world.clear_food(vec2(30.5, 30.5), 6.0);
+    for _ in 0..GROWTH_INTERVAL * 20 { world.update(); }
+    let lone = world.cell(10, 10).unwrap().food_level;
+    assert!(lone > 0.0, "lone bare cell is seeded by its neighbours");
+    assert!(world.cell(30, 30).unwrap().food_level < lone * 1e-3, "seeds barely reach the middle of a bare patch");
+}
+
+#[test]
+fn extra_food_wilts_back_to_capacity() {
+    let mut world = empty_world(6);
+    world.add_food(vec2(20.5, 20.5), 0.0, 50.0);
+    let capacity = world.biomes().get(world.cell(20, 20).unwrap().terrain).capacity;
+    for _ in 0..GROWTH_INTERVAL * 500 { world.update(); }
+    assert!(world.cell(20, 20).unwrap().food_level < capacity + 1.0);
+}
+
+#[test]
+fn grazing_to_zero_weakens_the_cell() {
+    let mut world = empty_world(8);
+    let (x, y) = (0..40).flat_map(|x| (0..40).map(move |y| (x, y)))
+        .find(|&(x, y)| world.biomes().get(world.cell(x, y).unwrap().terrain).speed == 1.0)
+        .unwrap();
+    // Повільний Валкарай з вузьким зором пасеться на місці, поки не з'їсть усе
+    world.spawn_valkarai(vec2(x as f32 + 0.5, y as f32 + 0.5), 0.08, 8.0, 0.0).unwrap();
+    for _ in 0..5 { world.update(); }
+    let grazed = world.cells().iter().flatten().filter(|c| c.vigor < 1.0).count();
+    assert!(grazed > 0, "an eaten-out cell loses vigor");
+    assert!(world.cells().iter().flatten().filter(|c| c.food_level == 0.0).all(|c| c.vigor < 1.0));
+}
+
+#[test]
+fn one_light_bite_does_no_damage() {
+    let mut world = empty_world(10);
+    let (x, y) = (0..40).flat_map(|x| (0..40).map(move |y| (x, y)))
+        .find(|&(x, y)| world.biomes().get(world.cell(x, y).unwrap().terrain).speed == 1.0)
+        .unwrap();
+    let center = vec2(x as f32 + 0.5, y as f32 + 0.5);
+    let capacity = world.biomes().get(world.cell(x, y).unwrap().terrain).capacity;
+    world.clear_food(center, 0.0);
+    world.add_food(center, 0.0, capacity);
+    world.spawn_valkarai(center, 0.08, 8.0, 0.0).unwrap();
+    world.update();
+    let cell = world.cell(x, y).unwrap();
+    assert!(cell.food_level < capacity && cell.food_level > 0.0, "one bite takes part of a full cell: {}", cell.food_level);
+    assert_eq!(cell.vigor, 1.0);
+}
+
+#[test]
+fn food_on_ocean_disappears() {
+    let config = SimConfig { grid_size: 60, initial_valkarai: 0, biome_set: "continental".into(), ..SimConfig::default() };
+    let mut world = World::with_config(config, 3);
+    world.erase_agents(vec2(30.0, 30.0), 100.0);
+    let (x, y) = (0..60).flat_map(|x| (0..60).map(move |y| (x, y)))
+        .find(|&(x, y)| world.cell(x, y).unwrap().terrain == Terrain::Ocean)
+        .unwrap();
+    world.add_food(vec2(x as f32 + 0.5, y as f32 + 0.5), 0.0, 30.0);
+    for _ in 0..GROWTH_INTERVAL { world.update(); }
+    assert_eq!(world.cell(x, y).unwrap().food_level, 0.0);
+}
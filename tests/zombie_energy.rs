@@ -0,0 +1,25 @@
+use quadrisrah_sim::{AgentKind, DeathCause, SimConfig, World};
+
+#[test]
+fn zombie_without_prey_starves() {
+    let config = SimConfig { initial_valkarai: 0, zombie_energy: 50.0, zombie_metabolism: 1.0, ..SimConfig::default() };
+    let mut world = World::with_config(config, 5);
+    let zombie = world.agents_of(AgentKind::Zombie).next().unwrap().id;
+    let mut starved = 0;
+    for _ in 0..60 {
+        world.update();
+        starved += world.last_events().zombies_starved;
+    }
+    assert_eq!(starved, 1);
+    assert!(world.agents().is_empty());
+    assert_eq!(world.lineage().get(zombie).unwrap().death.map(|d| d.1), Some(DeathCause::Starvation));
+}
+
+#[test]
+fn zombie_energy_drains_each_tick() {
+    let config = SimConfig { initial_valkarai: 0, zombie_metabolism: 0.5, ..SimConfig::default() };
+    let mut world = World::with_config(config, 5);
+    for _ in 0..10 { world.update(); }
+    let z = world.agents_of(AgentKind::Zombie).next().unwrap();
+    assert!((z.energy - (world.config().zombie_energy - 5.0)).abs() < 1e-3, "{}", z.energy);
+}